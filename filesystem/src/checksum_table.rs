@@ -1,27 +1,39 @@
 use crate::{ArchiveId, Cache, CacheResult, IndexId, REFERENCE_INDEX, crc32};
 
-pub fn build_checksum_table(cache: &Cache) -> CacheResult<Vec<u8>> {
-    let index_count = cache.index_count() - 1;
-    let mut table = Vec::with_capacity(index_count * 8);
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IndexChecksum {
+    pub crc: u32,
+    pub version: u32,
+}
 
-    for i in 0..index_count {
-        let index = IndexId::new(i as u8);
+pub fn index_checksums(cache: &Cache) -> Vec<IndexChecksum> {
+    let index_count = cache.index_count() - 1;
 
-        match cache.read_archive_raw(REFERENCE_INDEX, ArchiveId::new(i as u32)) {
-            Ok(data) => {
-                let crc = crc32(&data);
-                let version = cache
-                    .reference_table(index)
-                    .map(|rt| rt.version.unwrap_or(0))
-                    .unwrap_or(0);
+    (0..index_count)
+        .map(|i| {
+            let index = IndexId::new(i as u8);
 
-                table.extend_from_slice(&crc.to_be_bytes());
-                table.extend_from_slice(&version.to_be_bytes());
-            }
-            Err(_) => {
-                table.extend_from_slice(&[0u8; 8]);
+            match cache.read_archive_raw(REFERENCE_INDEX, ArchiveId::new(i as u32)) {
+                Ok(data) => IndexChecksum {
+                    crc: crc32(&data),
+                    version: cache
+                        .reference_table(index)
+                        .map(|rt| rt.version.unwrap_or(0))
+                        .unwrap_or(0),
+                },
+                Err(_) => IndexChecksum::default(),
             }
-        }
+        })
+        .collect()
+}
+
+pub fn build_checksum_table(cache: &Cache) -> CacheResult<Vec<u8>> {
+    let checksums = index_checksums(cache);
+    let mut table = Vec::with_capacity(checksums.len() * 8);
+
+    for checksum in &checksums {
+        table.extend_from_slice(&checksum.crc.to_be_bytes());
+        table.extend_from_slice(&checksum.version.to_be_bytes());
     }
 
     let mut response = Vec::with_capacity(5 + table.len());
@@ -17,7 +17,7 @@ mod reference;
 mod store;
 
 pub use cache::{Cache, CacheBuilder};
-pub use checksum_table::build_checksum_table;
+pub use checksum_table::{IndexChecksum, build_checksum_table, index_checksums};
 pub use codec::Compression;
 pub use error::{CacheError, CacheResult};
 pub use id::{ArchiveId, FileId, IndexId, REFERENCE_INDEX};
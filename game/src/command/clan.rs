@@ -0,0 +1,20 @@
+use macros::command;
+
+use super::CommandEntry;
+use crate::{content, player::Player, send_message};
+
+#[command(name = "clanban", rights = Standard, description = "Bans a player from your clan chat channel")]
+async fn ban(player: &mut Player, name: String) {
+    match content::ban_from_clan(player, &name).await {
+        true => send_message!(player, "{} has been banned from your channel.", name),
+        false => send_message!(player, "You do not have a clan chat channel."),
+    }
+}
+
+#[command(name = "clanunban", rights = Standard, description = "Lifts a ban from your clan chat channel")]
+async fn unban(player: &mut Player, name: String) {
+    match content::unban_from_clan(player, &name) {
+        true => send_message!(player, "{} is no longer banned from your channel.", name),
+        false => send_message!(player, "{} is not banned from your channel.", name),
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     world::Position,
 };
 
-#[command(name = "maxhit")]
+#[command(name = "maxhit", description = "Shows your current melee max hit")]
 async fn maxhit(player: &mut Player) {
     let (atk, style) = melee_atk(player);
     let max = max_hit(&atk);
@@ -22,13 +22,13 @@ async fn maxhit(player: &mut Player) {
     );
 }
 
-#[command(name = "spec")]
+#[command(name = "spec", description = "Toggles infinite special attack energy")]
 async fn spec(player: &mut Player) {
     let enabled = player.combat_mut().toggle_infinite_spec();
     send_message!(player, "Infinite spec: {}.", if enabled { "ON" } else { "OFF" });
 }
 
-#[command(name = "god")]
+#[command(name = "god", description = "Toggles maximum equipment bonuses")]
 async fn god(player: &mut Player) {
     let current = player.worn().bonuses();
     let enabled = current.str_bonus < 500;
@@ -55,7 +55,7 @@ async fn god(player: &mut Player) {
     send_message!(player, "God mode: {}.", if enabled { "ON" } else { "OFF" });
 }
 
-#[command(name = "proj")]
+#[command(name = "proj", description = "Fires a test projectile")]
 async fn proj(player: &mut Player, spotanim: Option<u16>) {
     let spotanim = spotanim.unwrap_or(19);
     let src = player.position;
@@ -2,19 +2,23 @@ use macros::command;
 
 use super::CommandEntry;
 use crate::{
+    content::CombatTarget,
     entity::{Hit, HitType},
     player::{Clientbound, Player},
     send_message,
 };
 
-#[command(name = "ndamage")]
+#[command(name = "ndamage", description = "Damages an npc by index")]
 async fn ndamage(player: &mut Player, index: usize, amount: u16) {
     let world = player.world();
     if !world.npcs.contains(index) {
         player.send_message("NPC not found").await;
         return;
     }
-    let died = world.npc_mut(index).damage(Hit::new(amount, HitType::Normal));
+    let died = world.npc_mut(index).damage(
+        Hit::new(amount, HitType::Normal),
+        Some(CombatTarget::Player(player.index)),
+    );
     if died {
         send_message!(player, "NPC {} died.", index);
     } else {
@@ -30,7 +34,7 @@ async fn ndamage(player: &mut Player, index: usize, amount: u16) {
     }
 }
 
-#[command(name = "damage")]
+#[command(name = "damage", description = "Damages yourself")]
 async fn damage(player: &mut Player, amount: u8) {
     let hit_type = if amount == 0 { HitType::Block } else { HitType::Normal };
     let died = player.hitpoints_mut().damage(Hit::new(amount as u16, hit_type));
@@ -0,0 +1,20 @@
+use macros::command;
+
+use super::CommandEntry;
+use crate::{player::Player, send_message};
+
+#[command(name = "commands", rights = Standard, aliases = ["help"], description = "Lists the commands available to you")]
+async fn commands(player: &mut Player) {
+    let mut entries: Vec<_> = inventory::iter::<CommandEntry>
+        .into_iter()
+        .filter(|e| e.rights <= player.rights)
+        .collect();
+    entries.sort_by_key(|e| e.name);
+
+    for entry in entries {
+        match entry.description {
+            "" => send_message!(player, "{}", entry.usage),
+            description => send_message!(player, "{} - {}", entry.usage, description),
+        }
+    }
+}
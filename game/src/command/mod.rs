@@ -1,6 +1,9 @@
+mod clan;
 mod combat;
 mod damage;
+mod help;
 mod inv;
+mod moderation;
 mod pos;
 mod seq;
 mod stat;
@@ -8,6 +11,8 @@ mod tele;
 
 use std::{collections::HashMap, future::Future, pin::Pin};
 
+use persistence::Rights;
+
 use crate::{player::Player, send_message};
 
 pub struct RawArgs(pub String);
@@ -16,22 +21,38 @@ type CommandFn = for<'a> fn(&'a mut Player, bool, &'a str) -> Pin<Box<dyn Future
 
 pub struct CommandEntry {
     pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub rights: Rights,
+    pub description: &'static str,
+    pub usage: &'static str,
     pub handle: CommandFn,
 }
 
 inventory::collect!(CommandEntry);
 
-static COMMANDS: std::sync::LazyLock<HashMap<&'static str, CommandFn>> = std::sync::LazyLock::new(|| {
+static COMMANDS: std::sync::LazyLock<HashMap<&'static str, &'static CommandEntry>> = std::sync::LazyLock::new(|| {
     let mut map = HashMap::new();
     for entry in inventory::iter::<CommandEntry> {
-        map.insert(entry.name, entry.handle);
+        map.insert(entry.name, entry);
+        for alias in entry.aliases {
+            map.insert(*alias, entry);
+        }
     }
     map
 });
 
 pub async fn dispatch_command(player: &mut Player, client_sent: bool, name: &str, args: &str) {
-    match COMMANDS.get(name) {
-        Some(handler) => handler(player, client_sent, args).await,
-        None => send_message!(player, "Unknown command: {}", name),
+    let Some(entry) = COMMANDS.get(name) else {
+        tracing::warn!(username = player.username, command = name, args, "Unknown command");
+        send_message!(player, "Unknown command: {}", name);
+        return;
+    };
+
+    if player.rights < entry.rights {
+        tracing::warn!(username = player.username, command = name, args, rights = ?player.rights, "Refused command");
+        send_message!(player, "Unknown command: {}", name);
+        return;
     }
+
+    (entry.handle)(player, client_sent, args).await;
 }
@@ -0,0 +1,242 @@
+use std::time::Duration;
+
+use macros::command;
+use persistence::{Punishment, PunishmentKind};
+
+use super::{CommandEntry, RawArgs};
+use crate::{
+    player::{Clientbound, Player},
+    send_message,
+    service::{PunishOutcome, PunishRequest},
+};
+
+struct Target {
+    name: String,
+    duration: Option<Duration>,
+    reason: String,
+}
+
+const MAX_HOURS: u64 = 10 * 365 * 24;
+
+/// Parses `<name> [hours] [reason]`, where zero or missing hours means permanent.
+fn parse_target(args: &str) -> Option<Target> {
+    let mut parts = args.split_whitespace().peekable();
+    let name = parts.next()?.to_string();
+    let hours = match parts.peek().filter(|h| h.bytes().all(|b| b.is_ascii_digit())) {
+        Some(h) => {
+            let hours = h.parse::<u64>().ok().filter(|&h| h <= MAX_HOURS)?;
+            parts.next();
+            Some(hours)
+        }
+        None => None,
+    };
+
+    Some(Target {
+        name,
+        duration: hours.filter(|&h| h > 0).map(|h| Duration::from_secs(h * 60 * 60)),
+        reason: parts.collect::<Vec<_>>().join(" "),
+    })
+}
+
+fn describe(duration: Option<Duration>) -> String {
+    match duration {
+        Some(d) => format!("for {} hours", d.as_secs() / (60 * 60)),
+        None => "permanently".to_string(),
+    }
+}
+
+#[command(name = "mute", rights = Moderator, description = "Mutes a player, for a number of hours or permanently")]
+async fn mute(player: &mut Player, args: RawArgs) {
+    punish(player, PunishmentKind::Mute, &args.0);
+}
+
+#[command(name = "ban", rights = Moderator, description = "Bans a player, for a number of hours or permanently")]
+async fn ban(player: &mut Player, args: RawArgs) {
+    punish(player, PunishmentKind::Ban, &args.0);
+}
+
+#[command(name = "ipban", rights = Moderator, description = "Bans the address a player last logged in from")]
+async fn ipban(player: &mut Player, args: RawArgs) {
+    punish(player, PunishmentKind::IpBan, &args.0);
+}
+
+#[command(name = "kick", rights = Moderator, description = "Logs a player out")]
+async fn kick(player: &mut Player, name: String) {
+    let world = player.world();
+    let Some(index) = world.find_player(&name) else {
+        send_message!(player, "{} is not online.", name);
+        return;
+    };
+    if index == player.index {
+        send_message!(player, "You can't kick yourself.");
+        return;
+    }
+
+    let mut target = world.player_mut(index);
+    if target.rights >= player.rights {
+        drop(target);
+        send_message!(player, "You can't kick that player.");
+        return;
+    }
+    target.logout().await;
+    target.disconnect();
+    let username = target.username.clone();
+    drop(target);
+
+    tracing::info!(staff = player.username, target = username, "Player kicked");
+    send_message!(player, "{} has been kicked.", username);
+}
+
+#[command(name = "unban", rights = Moderator, description = "Lifts a player's bans and IP bans")]
+async fn unban(player: &mut Player, name: String) {
+    revoke(player, &name, &[PunishmentKind::Ban, PunishmentKind::IpBan]);
+}
+
+#[command(name = "unmute", rights = Moderator, description = "Lifts a player's mutes")]
+async fn unmute(player: &mut Player, name: String) {
+    revoke(player, &name, &[PunishmentKind::Mute]);
+}
+
+fn punish(player: &mut Player, kind: PunishmentKind, args: &str) {
+    let command = match kind {
+        PunishmentKind::Mute => "mute",
+        PunishmentKind::Ban => "ban",
+        PunishmentKind::IpBan => "ipban",
+    };
+    let Some(target) = parse_target(args) else {
+        send_message!(
+            player,
+            "Usage: ::{} <lt>name<gt> [hours, up to {}] [reason]",
+            command,
+            MAX_HOURS
+        );
+        return;
+    };
+    let Some(moderation) = player.world().moderation() else {
+        return;
+    };
+
+    let request = PunishRequest {
+        target: target.name.clone(),
+        kind,
+        duration: target.duration,
+        reason: target.reason.clone(),
+        staff_id: player.account_id,
+        staff_rights: player.rights,
+    };
+    let world = player.world();
+    let (index, account_id) = (player.index, player.account_id);
+    tokio::spawn(async move {
+        let result = moderation.punish(request).await;
+        world.defer(index, account_id, move |player| {
+            Box::pin(punished(player, kind, command, target, result))
+        });
+    });
+}
+
+/// Reports the outcome of [`punish`] once the database has answered, back on the tick.
+async fn punished(
+    player: &mut Player,
+    kind: PunishmentKind,
+    command: &str,
+    target: Target,
+    result: anyhow::Result<PunishOutcome>,
+) {
+    let punishment = match result {
+        Ok(PunishOutcome::Applied(punishment)) => punishment,
+        Ok(PunishOutcome::UnknownAccount) => {
+            send_message!(player, "No account named {} exists.", target.name);
+            return;
+        }
+        Ok(PunishOutcome::Protected) => {
+            send_message!(player, "You can't {} that player.", command);
+            return;
+        }
+        Ok(PunishOutcome::UnknownAddress) => {
+            send_message!(player, "{} has no known address to ban.", target.name);
+            return;
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to Save Punishment");
+            send_message!(player, "Failed to save the punishment.");
+            return;
+        }
+    };
+
+    tracing::info!(
+        staff = player.username,
+        target = target.name,
+        kind = ?kind,
+        duration = describe(target.duration),
+        reason = target.reason,
+        "Punishment issued"
+    );
+    enforce(player, &target.name, &punishment).await;
+    send_message!(
+        player,
+        "{} has been {} {}.",
+        target.name,
+        match kind {
+            PunishmentKind::Mute => "muted",
+            PunishmentKind::Ban | PunishmentKind::IpBan => "banned",
+        },
+        describe(target.duration)
+    );
+}
+
+/// Applies a new punishment to its target straight away if they are online.
+async fn enforce(player: &Player, name: &str, punishment: &Punishment) {
+    let world = player.world();
+    let Some(index) = world.find_player(name).filter(|&i| i != player.index) else {
+        return;
+    };
+
+    let mut target = world.player_mut(index);
+    match punishment.kind {
+        PunishmentKind::Mute => {
+            target.mute_mut().set(punishment.remaining());
+            target.send_message("You have been muted.").await;
+        }
+        PunishmentKind::Ban | PunishmentKind::IpBan => {
+            target.logout().await;
+            target.disconnect();
+        }
+    }
+}
+
+fn revoke(player: &mut Player, name: &str, kinds: &'static [PunishmentKind]) {
+    let Some(moderation) = player.world().moderation() else {
+        return;
+    };
+
+    let world = player.world();
+    let (index, account_id) = (player.index, player.account_id);
+    let name = name.to_string();
+    tokio::spawn(async move {
+        let result = moderation.revoke(&name, kinds).await;
+        world.defer(index, account_id, move |player| {
+            Box::pin(revoked(player, name, kinds, result))
+        });
+    });
+}
+
+async fn revoked(player: &mut Player, name: String, kinds: &[PunishmentKind], result: anyhow::Result<Option<u64>>) {
+    match result {
+        Ok(Some(0)) => send_message!(player, "{} has nothing to lift.", name),
+        Ok(Some(_)) => {
+            tracing::info!(staff = player.username, target = name, kinds = ?kinds, "Punishment lifted");
+            send_message!(player, "Lifted the punishments on {}.", name);
+            if kinds.contains(&PunishmentKind::Mute) {
+                let world = player.world();
+                if let Some(index) = world.find_player(&name).filter(|&i| i != player.index) {
+                    world.player_mut(index).mute_mut().clear();
+                }
+            }
+        }
+        Ok(None) => send_message!(player, "No account named {} exists.", name),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to Lift Punishment");
+            send_message!(player, "Failed to lift the punishment.");
+        }
+    }
+}
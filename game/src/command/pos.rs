@@ -3,7 +3,7 @@ use macros::command;
 use super::CommandEntry;
 use crate::{player::Player, send_message};
 
-#[command(name = "pos")]
+#[command(name = "pos", rights = Moderator, aliases = ["coords"], description = "Shows your current position")]
 async fn handle(player: &mut Player) {
     let pos = player.position;
     send_message!(player, "Position: x={}, y={}, plane={}", pos.x, pos.y, pos.plane);
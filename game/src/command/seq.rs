@@ -2,12 +2,12 @@ use macros::command;
 
 use crate::{command::CommandEntry, player::Player, send_message};
 
-#[command(name = "seq")]
+#[command(name = "seq", description = "Plays an animation")]
 async fn seq(player: &mut Player, id: u16, speed: Option<u8>) {
     player.seq(id).speed(speed.unwrap_or(0));
 }
 
-#[command(name = "spotanim")]
+#[command(name = "spotanim", description = "Plays a spot animation")]
 async fn spotanim(player: &mut Player, id: u16, speed: Option<u16>, height: Option<u16>, rotation: Option<u8>) {
     player
         .spot_anim(id)
@@ -16,7 +16,7 @@ async fn spotanim(player: &mut Player, id: u16, speed: Option<u16>, height: Opti
         .rotation(rotation.unwrap_or(0));
 }
 
-#[command(name = "npc_seq")]
+#[command(name = "npc_seq", description = "Plays an animation on an npc")]
 async fn npc_seq(player: &mut Player, npc_index: usize, id: u16, speed: Option<u8>) {
     let world = player.world();
     if world.npcs.contains(npc_index) {
@@ -26,7 +26,7 @@ async fn npc_seq(player: &mut Player, npc_index: usize, id: u16, speed: Option<u
     }
 }
 
-#[command(name = "npc_spotanim")]
+#[command(name = "npc_spotanim", description = "Plays a spot animation on an npc")]
 async fn npc_spotanim(
     player: &mut Player,
     npc_index: usize,
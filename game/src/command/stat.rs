@@ -8,7 +8,7 @@ use crate::{
     send_message,
 };
 
-#[command(name = "addxp")]
+#[command(name = "addxp", description = "Adds experience to a stat")]
 async fn add_xp(player: &mut Player, stat_id: usize, xp: f64) {
     if let Ok(stat) = Stat::try_from_primitive(stat_id) {
         player.stat_mut().add_xp(stat, xp).await;
@@ -19,7 +19,7 @@ async fn add_xp(player: &mut Player, stat_id: usize, xp: f64) {
     player.send_message("Invalid stat id (0-23)").await;
 }
 
-#[command(name = "setlevel")]
+#[command(name = "setlevel", description = "Sets the level of a stat")]
 async fn setlevel(player: &mut Player, stat_id: usize, level: u8) {
     let stat = match Stat::try_from(stat_id) {
         Ok(s) => s,
@@ -41,7 +41,7 @@ async fn setlevel(player: &mut Player, stat_id: usize, level: u8) {
     send_message!(player, "Set {:?} to level {}", stat, level);
 }
 
-#[command(name = "random_stats")]
+#[command(name = "random_stats", description = "Sets every stat to a random high level")]
 async fn random_stats(player: &mut Player) {
     let levels: Vec<_> = {
         let mut rng = rand::rng();
@@ -3,7 +3,7 @@ use macros::command;
 use super::{CommandEntry, RawArgs};
 use crate::{player::Player, send_message, world::Position};
 
-#[command(name = "tele")]
+#[command(name = "tele", aliases = ["teleport"], description = "Teleports to a coordinate")]
 async fn handle(player: &mut Player, client_sent: bool, args: RawArgs) {
     let (x, y, plane) = if client_sent {
         let parts: Vec<i32> = args.0.split(',').filter_map(|s| s.parse().ok()).collect();
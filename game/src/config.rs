@@ -8,10 +8,27 @@ pub struct LogConfig {
     pub level: String,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SafeZone {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(default)]
 pub struct GameConfig {
+    pub world_id: u16,
     pub client_version: u32,
+    pub crc_allow_list: Vec<u8>,
+    pub reconnect_grace_secs: u64,
+    pub max_players: usize,
+    pub reserved_slots: usize,
+    pub login_queue: bool,
+    pub login_queue_timeout_secs: u64,
+    pub safe_zones: Vec<SafeZone>,
+    pub auto_register: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -39,6 +56,17 @@ impl Default for LogConfig {
 
 impl Default for GameConfig {
     fn default() -> Self {
-        Self { client_version: 592 }
+        Self {
+            world_id: 1,
+            client_version: 592,
+            crc_allow_list: Vec::new(),
+            reconnect_grace_secs: 60,
+            max_players: 2000,
+            reserved_slots: 10,
+            login_queue: false,
+            login_queue_timeout_secs: 30,
+            safe_zones: Vec::new(),
+            auto_register: false,
+        }
     }
 }
@@ -0,0 +1,305 @@
+mod setup;
+
+use net::ClanMemberEntry;
+use util::{format_display_name, normalize_name};
+
+use crate::{
+    player::{Clientbound, Player, next_message_id},
+    provider,
+    world::{CLAN_MAX_MEMBERS, ClanChannel, ClanMember, ClanRank, World},
+};
+
+#[derive(Clone)]
+struct ChannelUpdate {
+    owner: String,
+    name: String,
+    kick_rank: i8,
+    members: Vec<ClanMemberEntry>,
+}
+
+fn channel_update(channel: &ClanChannel, world_id: u16) -> ChannelUpdate {
+    ChannelUpdate {
+        owner: format_display_name(&channel.owner),
+        name: channel.name.clone(),
+        kick_rank: channel.kick_rank.into(),
+        members: channel
+            .members
+            .iter()
+            .map(|m| ClanMemberEntry {
+                name: format_display_name(&m.name),
+                world: world_id,
+                rank: channel.rank_of(&m.name, m.rights).into(),
+            })
+            .collect(),
+    }
+}
+
+async fn sync(world: &World, player: &mut Player, owner: &str) {
+    let Some((update, members)) = snapshot(world, owner) else {
+        return;
+    };
+
+    for index in members {
+        if index == player.index {
+            send_update(player, update.clone()).await;
+        } else if world.players.contains(index) {
+            send_update(&mut world.player_mut(index), update.clone()).await;
+        }
+    }
+}
+
+fn snapshot(world: &World, owner: &str) -> Option<(ChannelUpdate, Vec<usize>)> {
+    world
+        .clans
+        .with(owner, |c| (channel_update(c, world.world_id()), c.member_indices()))
+}
+
+async fn send_update(player: &mut Player, update: ChannelUpdate) {
+    player
+        .update_clan_channel(update.owner, update.name, update.kick_rank, update.members)
+        .await;
+}
+
+pub async fn join_clan(player: &mut Player, owner: &str) {
+    leave_clan(player).await;
+
+    let owner = normalize_name(owner);
+    let member = ClanMember {
+        index: player.index,
+        name: normalize_name(&player.username),
+        rights: player.rights,
+    };
+    let world = player.world();
+    let joined = world.clans.with_mut(&owner, |c| {
+        if c.is_banned(&member.name) {
+            return Err("You are not allowed to join this user's clan chat channel.");
+        }
+        if c.rank_of(&member.name, member.rights) < c.join_rank {
+            return Err("You do not have a high enough rank to join this clan chat channel.");
+        }
+        if c.members.len() >= CLAN_MAX_MEMBERS {
+            return Err("The channel you tried to join is full.");
+        }
+        c.members.push(member);
+        Ok(c.name.clone())
+    });
+
+    let name = match joined {
+        Some(Ok(name)) => name,
+        Some(Err(reason)) => return player.send_message(reason).await,
+        None => {
+            return player
+                .send_message("The channel you tried to join does not exist.")
+                .await;
+        }
+    };
+
+    player.clan_mut().set_channel(Some(owner.clone()));
+    player
+        .send_message(format!("Now talking in clan channel {}", name))
+        .await;
+    player
+        .send_message("To talk, start each line of chat with the / symbol.")
+        .await;
+    sync(&world, player, &owner).await;
+}
+
+pub async fn leave_clan(player: &mut Player) {
+    let Some(owner) = player.clan_mut().take_channel() else {
+        return;
+    };
+    let world = player.world();
+    remove_member(&world, player, &owner).await;
+    player.leave_clan_channel().await;
+}
+
+async fn remove_member(world: &World, player: &mut Player, owner: &str) {
+    world.clans.with_mut(owner, |c| c.remove_member(player.index));
+    sync(world, player, owner).await;
+}
+
+pub async fn rejoin_clan(player: &mut Player) {
+    let Some(owner) = player.clan_mut().take_channel() else {
+        return;
+    };
+    join_clan(player, &owner).await;
+}
+
+/// Takes no guard on the leaving player, so logout only locks one player at a time.
+pub async fn clan_logout(world: &World, index: usize, owner: &str) {
+    world.clans.with_mut(owner, |c| c.remove_member(index));
+    let Some((update, members)) = snapshot(world, owner) else {
+        return;
+    };
+
+    for member in members {
+        if member != index && world.players.contains(member) {
+            send_update(&mut world.player_mut(member), update.clone()).await;
+        }
+    }
+}
+
+pub async fn send_clan_message(player: &mut Player, text: &str) {
+    let Some(owner) = player.clan().channel().map(str::to_string) else {
+        player.send_message("You are not in a clan chat channel.").await;
+        return;
+    };
+
+    let name = normalize_name(&player.username);
+    let world = player.world();
+    let Some((channel, members, allowed)) = world.clans.with(&owner, |c| {
+        let allowed = c.rank_of(&name, player.rights) >= c.talk_rank;
+        (c.name.clone(), c.member_indices(), allowed)
+    }) else {
+        return;
+    };
+    if !allowed {
+        player
+            .send_message("You do not have a high enough rank to talk in this clan chat channel.")
+            .await;
+        return;
+    }
+
+    let payload = provider::encode_huffman(text);
+    let message_id = next_message_id();
+    let from = player.username.clone();
+    let rights: u8 = player.rights.into();
+    for index in members {
+        if index == player.index {
+            player
+                .message_clan_channel(
+                    from.clone(),
+                    channel.clone(),
+                    message_id,
+                    rights,
+                    text.len(),
+                    payload.clone(),
+                )
+                .await;
+            continue;
+        }
+        if !world.players.contains(index) {
+            continue;
+        }
+        let mut other = world.player_mut(index);
+        if other.social().is_ignoring(&name) {
+            continue;
+        }
+        other
+            .message_clan_channel(
+                from.clone(),
+                channel.clone(),
+                message_id,
+                rights,
+                text.len(),
+                payload.clone(),
+            )
+            .await;
+    }
+}
+
+pub async fn kick_clan_member(player: &mut Player, target: &str) {
+    let Some(owner) = player.clan().channel().map(str::to_string) else {
+        return;
+    };
+
+    let target = normalize_name(target);
+    let name = normalize_name(&player.username);
+    let world = player.world();
+    let kicked = world.clans.with_mut(&owner, |c| {
+        let rank = c.rank_of(&name, player.rights);
+        if rank < c.kick_rank {
+            return Err("You do not have a high enough rank to kick in this clan chat channel.");
+        }
+        let Some(member) = c.members.iter().find(|m| m.name == target) else {
+            return Err("That player is not in this clan chat channel.");
+        };
+        if c.rank_of(&member.name, member.rights) >= rank {
+            return Err("You cannot kick a user with an equal or higher rank.");
+        }
+        let index = member.index;
+        c.remove_member(index);
+        c.kick(&target);
+        Ok(index)
+    });
+
+    match kicked {
+        Some(Ok(index)) => {
+            eject(&world, index, "You have been kicked from the channel.").await;
+            sync(&world, player, &owner).await;
+            let msg = format!("{} has been kicked from the channel.", format_display_name(&target));
+            player.send_message(msg).await;
+        }
+        Some(Err(reason)) => player.send_message(reason).await,
+        None => {}
+    }
+}
+
+async fn eject(world: &World, index: usize, reason: &str) {
+    if !world.players.contains(index) {
+        return;
+    }
+    let mut other = world.player_mut(index);
+    other.clan_mut().set_channel(None);
+    other.leave_clan_channel().await;
+    other.send_message(reason).await;
+}
+
+pub async fn set_clan_rank(player: &mut Player, name: &str, rank: i8) {
+    let name = normalize_name(name);
+    let Ok(rank) = ClanRank::try_from(rank) else { return };
+    if !(ClanRank::Friend..=ClanRank::General).contains(&rank) || !player.social().has_friend(&name) {
+        return;
+    }
+
+    let owner = normalize_name(&player.username);
+    let world = player.world();
+    if world.clans.with_mut(&owner, |c| c.ranks.insert(name, rank)).is_some() {
+        sync(&world, player, &owner).await;
+    }
+}
+
+pub async fn sync_clan_friend(player: &mut Player, name: &str, added: bool) {
+    let name = normalize_name(name);
+    let owner = normalize_name(&player.username);
+    let world = player.world();
+    let changed = world.clans.with_mut(&owner, |c| match added {
+        true => c.ranks.insert(name.clone(), ClanRank::Friend).is_none(),
+        false => c.ranks.remove(&name).is_some(),
+    });
+    if changed == Some(true) {
+        sync(&world, player, &owner).await;
+    }
+}
+
+pub async fn ban_from_clan(player: &mut Player, name: &str) -> bool {
+    let name = normalize_name(name);
+    let owner = normalize_name(&player.username);
+    if name == owner {
+        return false;
+    }
+    let world = player.world();
+    let Some(member) = world.clans.with_mut(&owner, |c| {
+        c.bans.insert(name.clone());
+        let index = c.members.iter().find(|m| m.name == name).map(|m| m.index)?;
+        c.remove_member(index);
+        Some(index)
+    }) else {
+        return false;
+    };
+
+    if let Some(index) = member {
+        eject(&world, index, "You have been banned from the channel.").await;
+        sync(&world, player, &owner).await;
+    }
+    true
+}
+
+pub fn unban_from_clan(player: &Player, name: &str) -> bool {
+    let owner = normalize_name(&player.username);
+    player
+        .world()
+        .clans
+        .with_mut(&owner, |c| c.bans.remove(&normalize_name(name)))
+        .unwrap_or(false)
+}
@@ -0,0 +1,131 @@
+use net::Op;
+use util::normalize_name;
+
+use crate::{
+    player::{Clientbound, InterfaceSlot, Player, clan_chat as ui},
+    world::{ClanChannel, ClanRank},
+};
+
+const NAME_COMPONENT: u16 = 22;
+const JOIN_COMPONENT: u16 = 23;
+const TALK_COMPONENT: u16 = 24;
+const KICK_COMPONENT: u16 = 25;
+
+const MAX_NAME_LEN: usize = 12;
+
+#[derive(Clone, Copy)]
+enum Setting {
+    Join,
+    Talk,
+    Kick,
+}
+
+#[macros::on_interface(interface = 589, component = 9, op = Op1)]
+async fn clan_setup() {
+    player.interface_mut().open_slot(InterfaceSlot::Modal, ui::SETUP).await;
+    refresh(&mut player).await;
+}
+
+#[macros::on_interface(interface = 590, component = 22, op = Op1)]
+async fn clan_setup_name() {
+    player
+        .name_prompt_mut()
+        .prompt("Enter chat prefix:", |p: &mut Player, name| Box::pin(set_name(p, name)))
+        .await;
+}
+
+#[macros::on_interface(interface = 590, component = 23)]
+async fn clan_setup_join() {
+    set_rank(&mut player, Setting::Join, op).await;
+}
+
+#[macros::on_interface(interface = 590, component = 24)]
+async fn clan_setup_talk() {
+    set_rank(&mut player, Setting::Talk, op).await;
+}
+
+#[macros::on_interface(interface = 590, component = 25)]
+async fn clan_setup_kick() {
+    set_rank(&mut player, Setting::Kick, op).await;
+}
+
+fn rank_for(op: Op) -> Option<ClanRank> {
+    Some(match op {
+        Op::Op1 => ClanRank::Anyone,
+        Op::Op2 => ClanRank::Friend,
+        Op::Op3 => ClanRank::Recruit,
+        Op::Op4 => ClanRank::Corporal,
+        Op::Op5 => ClanRank::Sergeant,
+        Op::Op6 => ClanRank::Lieutenant,
+        Op::Op7 => ClanRank::Captain,
+        Op::Op8 => ClanRank::General,
+        Op::Op9 => ClanRank::Owner,
+        _ => return None,
+    })
+}
+
+async fn refresh(player: &mut Player) {
+    let owner = normalize_name(&player.username);
+    let settings = player
+        .world()
+        .clans
+        .with(&owner, |c| (c.name.clone(), c.join_rank, c.talk_rank, c.kick_rank));
+
+    let Some((name, join, talk, kick)) = settings else {
+        player.if_set_text(ui::SETUP, NAME_COMPONENT, "Chat disabled").await;
+        return;
+    };
+    player.if_set_text(ui::SETUP, NAME_COMPONENT, name).await;
+    player.if_set_text(ui::SETUP, JOIN_COMPONENT, join.label()).await;
+    player.if_set_text(ui::SETUP, TALK_COMPONENT, talk.label()).await;
+    player.if_set_text(ui::SETUP, KICK_COMPONENT, kick.label()).await;
+}
+
+async fn set_name(player: &mut Player, name: String) {
+    let name = name.trim().to_string();
+    let valid = name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b' ');
+    if name.is_empty() || name.len() > MAX_NAME_LEN || !valid {
+        player.send_message("Invalid chat prefix.").await;
+        return;
+    }
+
+    let owner = normalize_name(&player.username);
+    let world = player.world();
+    if world.clans.with_mut(&owner, |c| c.name = name.clone()).is_none() {
+        let mut channel = ClanChannel::new(player.player_id, owner.clone(), name);
+        channel.ranks = player
+            .social()
+            .friends()
+            .iter()
+            .map(|f| (f.clone(), ClanRank::Friend))
+            .collect();
+        world.clans.insert(channel);
+    }
+
+    refresh(player).await;
+    super::sync(&world, player, &owner).await;
+}
+
+async fn set_rank(player: &mut Player, setting: Setting, op: Op) {
+    let Some(rank) = rank_for(op) else { return };
+    if matches!(setting, Setting::Kick) && rank < ClanRank::Recruit {
+        return;
+    }
+
+    let owner = normalize_name(&player.username);
+    let world = player.world();
+    let updated = world.clans.with_mut(&owner, |c| match setting {
+        Setting::Join => c.join_rank = rank,
+        Setting::Talk => c.talk_rank = rank,
+        Setting::Kick => c.kick_rank = rank,
+    });
+    if updated.is_none() {
+        player
+            .send_message("You need to set a chat prefix before changing these settings.")
+            .await;
+        return;
+    }
+
+    refresh(player).await;
+    super::sync(&world, player, &owner).await;
+}
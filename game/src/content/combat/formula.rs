@@ -6,12 +6,15 @@ pub struct AttackRoll {
     pub str_level: u16,
     pub atk_bonus: i16,
     pub str_bonus: i16,
+    pub atk_prayer: f64,
+    pub str_prayer: f64,
     pub stance: WeaponStance,
 }
 
 pub struct DefenceRoll {
     pub def_level: u16,
     pub def_bonus: i16,
+    pub def_prayer: f64,
     pub stance: WeaponStance,
 }
 
@@ -39,22 +42,33 @@ fn stance_def_bonus(stance: WeaponStance) -> u32 {
     }
 }
 
-fn effective_level(base: u16, stance_bonus: u32) -> u32 {
-    base as u32 + stance_bonus + 8
+fn effective_level(base: u16, prayer: f64, stance_bonus: u32) -> u32 {
+    (base as f64 * prayer) as u32 + stance_bonus + 8
 }
 
 pub fn max_hit(attacker: &AttackRoll) -> u16 {
-    let eff_str = effective_level(attacker.str_level, stance_str_bonus(attacker.stance));
+    let eff_str = effective_level(
+        attacker.str_level,
+        attacker.str_prayer,
+        stance_str_bonus(attacker.stance),
+    );
     let bonus = attacker.str_bonus.max(0) as u32 + 64;
     ((eff_str * bonus + 320) / 640) as u16
 }
 
 pub fn accuracy(attacker: &AttackRoll, defender: &DefenceRoll, atk_type: AttackType) -> bool {
-    let _ = atk_type; // atk_type already factored into the bonus values passed in
-    let eff_atk = effective_level(attacker.atk_level, stance_atk_bonus(attacker.stance));
+    let stance_bonus = match atk_type {
+        AttackType::Magic => 0,
+        _ => stance_atk_bonus(attacker.stance),
+    };
+    let eff_atk = effective_level(attacker.atk_level, attacker.atk_prayer, stance_bonus);
     let atk_roll = eff_atk * (attacker.atk_bonus.max(0) as u32 + 64);
 
-    let eff_def = effective_level(defender.def_level, stance_def_bonus(defender.stance));
+    let eff_def = effective_level(
+        defender.def_level,
+        defender.def_prayer,
+        stance_def_bonus(defender.stance),
+    );
     let def_roll = eff_def * (defender.def_bonus.max(0) as u32 + 64);
 
     let chance = if atk_roll > def_roll {
@@ -66,6 +80,14 @@ pub fn accuracy(attacker: &AttackRoll, defender: &DefenceRoll, atk_type: AttackT
     rand::rng().random::<f64>() < chance
 }
 
+pub fn magic_max_hit(base: u16, dmg_bonus: i16) -> u16 {
+    (base as u32 * (100 + dmg_bonus.max(0) as u32) / 100) as u16
+}
+
+pub fn magic_def_level(magic: u16, defence: u16) -> u16 {
+    ((magic as u32 * 7 + defence as u32 * 3) / 10) as u16
+}
+
 pub fn roll_damage(max: u16) -> u16 {
     if max == 0 {
         return 0;
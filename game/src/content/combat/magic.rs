@@ -0,0 +1,118 @@
+use filesystem::{AttackType, WeaponStance, XpType};
+
+use super::{
+    CombatTarget, PendingHit, Projectile,
+    formula::{AttackRoll, accuracy, magic_max_hit, roll_damage},
+    player::{award_combat_xp, resolve_style},
+    queue_hit,
+    ranged::{center_of, distance_to_target, eucl_dist, flight_time, hit_delay},
+    send_projectile,
+};
+use crate::{
+    content::{CombatSpell, check_runes, remove_runes},
+    entity::HitType,
+    player::{Player, Prayer, Stat},
+};
+
+pub const ATTACK_RANGE: i32 = 10;
+pub const ATTACK_SPEED: u16 = 5;
+
+const SPLASH_SPOTANIM: u16 = 85;
+const PROJ_START_HEIGHT: u8 = 43;
+const PROJ_END_HEIGHT: u8 = 31;
+const PROJ_DELAY: u16 = 51;
+const PROJ_SLOPE: u8 = 16;
+const PROJ_SPEED: u16 = 51;
+
+fn magic_atk(player: &Player) -> AttackRoll {
+    let magic = player.stat().level(Stat::Magic) as u16;
+    AttackRoll {
+        atk_level: magic,
+        str_level: magic,
+        atk_bonus: player.worn().bonuses().atk_magic,
+        str_bonus: 0,
+        atk_prayer: player.prayer().multiplier(Prayer::magic_multiplier),
+        str_prayer: 1.0,
+        stance: WeaponStance::Accurate,
+    }
+}
+
+pub fn spell_style(player: &Player) -> bool {
+    resolve_style(player).atk_type == AttackType::Magic
+}
+
+pub fn autocast_spell(player: &Player) -> Option<&'static CombatSpell> {
+    spell_style(player).then(|| player.combat().autocast()).flatten()
+}
+
+pub async fn fire_magic_attack(player: &mut Player, target: CombatTarget, spell: &'static CombatSpell) -> bool {
+    if player.stat().level(Stat::Magic) < spell.level {
+        crate::send_message!(player, "You need a Magic level of {} to cast this spell.", spell.level);
+        return false;
+    }
+    if !check_runes(player, spell.runes).await {
+        return false;
+    }
+    remove_runes(player, spell.runes).await;
+
+    let atk = magic_atk(player);
+    let world = player.world();
+    let def = target.def(&world, AttackType::Magic);
+    let target_pos = target.position(&world);
+    let target_size = target.size(&world);
+    drop(world);
+
+    let hit = accuracy(&atk, &def, AttackType::Magic);
+    let damage = if hit { roll_damage(magic_max_hit(spell.max_hit, player.worn().bonuses().magic_dmg)) } else { 0 };
+
+    player.seq(spell.seq);
+    drop(player.spot_anim(spell.cast_spotanim).height(100));
+
+    let src = player.position;
+    let eucl = eucl_dist(src, target_pos);
+    let proj = Projectile {
+        spotanim: spell.proj_spotanim,
+        src,
+        dst: center_of(target_pos, target_size),
+        target,
+        start_height: PROJ_START_HEIGHT,
+        end_height: PROJ_END_HEIGHT,
+        start_cycle: PROJ_DELAY,
+        end_cycle: PROJ_DELAY + flight_time(PROJ_SPEED, eucl),
+        slope: PROJ_SLOPE,
+        angle: 0,
+    };
+    let world = player.world();
+    send_projectile(player, &world, &proj).await;
+
+    let delay = hit_delay(distance_to_target(src, target_pos, target_size));
+    let spotanim = if hit { spell.hit_spotanim } else { SPLASH_SPOTANIM };
+    match target {
+        CombatTarget::Npc(i) => drop(world.npc_mut(i).spot_anim(spotanim).height(100).speed(delay * 30)),
+        CombatTarget::Player(i) => drop(world.player_mut(i).spot_anim(spotanim).height(100).speed(delay * 30)),
+    }
+
+    if hit {
+        queue_hit(
+            &world,
+            PendingHit {
+                target,
+                attacker: CombatTarget::Player(player.index),
+                damage,
+                hit_type: if damage == 0 { HitType::Block } else { HitType::Normal },
+                delay,
+            },
+        );
+    }
+    drop(world);
+
+    player.stat_mut().add_xp(Stat::Magic, spell.xp).await;
+    if hit {
+        let xp_type = match resolve_style(player).xp_type {
+            XpType::MagicAndDefence => XpType::MagicAndDefence,
+            _ => XpType::Magic,
+        };
+        award_combat_xp(xp_type, damage).await;
+    }
+    true
+}
@@ -1,4 +1,5 @@
 mod formula;
+mod magic;
 mod melee;
 mod npc;
 mod npcs;
@@ -10,16 +11,17 @@ mod specials;
 
 pub use formula::{AttackRoll, DefenceRoll, accuracy, max_hit, roll_damage};
 pub use npc::{
-    NpcAttackResult, NpcCombatScript, NpcHit, npc_center, npc_melee_atk, npc_size, player_def, roll_npc_hit,
+    NpcAttackResult, NpcCombatScript, NpcHit, hunt, npc_center, npc_melee_atk, npc_size, player_def, roll_npc_hit,
 };
 pub use player::melee_atk;
 pub use special::get as get_spec;
 
 use crate::{
+    content::follow,
     entity::{Hit, HitType},
     player::{Clientbound, FaceEntityMask as PlayerFaceEntityMask},
     provider,
-    world::{Position, World, can_interact_rect, find_path_adjacent_rect, has_line_of_sight},
+    world::{Position, World, can_interact_rect, has_line_of_sight},
 };
 
 pub struct PendingHit {
@@ -30,7 +32,7 @@ pub struct PendingHit {
     pub delay: u16,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CombatTarget {
     Npc(usize),
     Player(usize),
@@ -74,7 +76,7 @@ impl CombatTarget {
 
     fn apply_hit(self, world: &World, damage: u16, hit_type: HitType, attacker: CombatTarget) {
         match self {
-            Self::Npc(i) => apply_hit_npc(world, i, damage, hit_type),
+            Self::Npc(i) => apply_hit_npc(world, i, damage, hit_type, attacker),
             Self::Player(i) => apply_hit_player(world, i, damage, hit_type, attacker),
         }
     }
@@ -178,8 +180,10 @@ pub fn roll_hit(atk: &AttackRoll, def: &DefenceRoll, atk_type: filesystem::Attac
     }
 }
 
-fn apply_hit_npc(world: &World, npc_index: usize, damage: u16, hit_type: HitType) {
-    world.npc_mut(npc_index).damage(Hit::new(damage, hit_type));
+fn apply_hit_npc(world: &World, npc_index: usize, damage: u16, hit_type: HitType, attacker: CombatTarget) {
+    world
+        .npc_mut(npc_index)
+        .damage(Hit::new(damage, hit_type), Some(attacker));
 
     let npc = world.npc(npc_index);
     let play_block = !npc.is_dying() && !npc.has_seq();
@@ -196,6 +200,14 @@ fn apply_hit_player(world: &World, target_index: usize, damage: u16, hit_type: H
     let has_seq = target.has_seq();
     drop(target);
 
+    let last_attacker = match attacker {
+        CombatTarget::Player(index) if world.players.contains(index) => Some((index, world.player(index).account_id)),
+        _ => None,
+    };
+    world
+        .player_mut(target_index)
+        .hitpoints_mut()
+        .set_last_attacker(last_attacker);
     let died = world
         .player_mut(target_index)
         .hitpoints_mut()
@@ -216,6 +228,7 @@ pub async fn start_combat(target: CombatTarget) {
     player.entity.face_target = Some(target_face);
     player.player_info.add_mask(PlayerFaceEntityMask(target_face));
 
+    let manual_spell = player.combat_mut().take_queued_spell();
     let mut npc_engaged = false;
     let mut player_cd: u16 = if let CombatTarget::Player(target_index) = target {
         let world = player.world();
@@ -226,6 +239,7 @@ pub async fn start_combat(target: CombatTarget) {
         0
     };
 
+    let mut last_target_pos = None;
     loop {
         let world = player.world();
         if !target.alive(&world) {
@@ -236,6 +250,11 @@ pub async fn start_combat(target: CombatTarget) {
         let target_size = target.size(&world);
         drop(world);
 
+        if follow::lost_target(&player, last_target_pos, target_pos) {
+            break;
+        }
+        last_target_pos = Some(target_pos);
+
         if player.combat_mut().consume_eat_delay() {
             player_cd = player_cd.max(1);
         }
@@ -248,12 +267,19 @@ pub async fn start_combat(target: CombatTarget) {
             player_cd = 1;
         }
 
+        let spell = manual_spell.or_else(|| magic::autocast_spell(&player));
+        if spell.is_none() && magic::spell_style(&player) {
+            crate::send_message!(&mut player, "You need to select a spell to autocast.");
+            break;
+        }
+
         let ranged = player::is_ranged_weapon(&player);
         let collision = provider::get_collision();
-        let in_range = if ranged {
+        let in_range = if ranged || spell.is_some() {
+            let range = if spell.is_some() { magic::ATTACK_RANGE } else { ranged::attack_range(&player) };
             let dist = ranged::distance_to_target(player.position, target_pos, target_size);
             let los_tile = ranged::nearest_tile(player.position, target_pos, target_size);
-            dist <= ranged::attack_range(&player)
+            dist <= range
                 && player.position.plane == target_pos.plane
                 && has_line_of_sight(collision, player.position, los_tile)
         } else {
@@ -271,7 +297,12 @@ pub async fn start_combat(target: CombatTarget) {
             npc_engaged = true;
         }
 
-        if in_range && player_cd > 0 && player.combat().spec_enabled() && special::is_instant(&player) {
+        if in_range
+            && player_cd > 0
+            && spell.is_none()
+            && player.combat().spec_enabled()
+            && special::is_instant(&player)
+        {
             let (atk, style) = player::melee_atk(&player);
             let def = target.def(&player.world(), style.atk_type);
             if let Some(result) = special::try_execute(&mut player, target, &atk, &def, style.atk_type) {
@@ -281,20 +312,24 @@ pub async fn start_combat(target: CombatTarget) {
         } else if in_range && player_cd == 0 {
             player.entity.stop();
 
-            if ranged {
+            if let Some(spell) = spell {
+                if !magic::fire_magic_attack(&mut player, target, spell).await || manual_spell.is_some() {
+                    break;
+                }
+                player_cd = magic::ATTACK_SPEED;
+            } else if ranged {
                 if !ranged::has_ammo(&player) {
                     crate::send_message!(&mut player, "There is no ammo left in your quiver.");
                     break;
                 }
                 ranged::fire_ranged_attack(&mut player, target).await;
+                player_cd = player::weapon_atk_speed(&player);
             } else {
                 melee::fire_melee_attack(&mut player, target).await;
+                player_cd = player::weapon_atk_speed(&player);
             }
-
-            player_cd = player::weapon_atk_speed(&player);
         } else if !in_range {
-            player.entity.walk_queue =
-                find_path_adjacent_rect(player.position, target_pos, target_size, target_size, 0);
+            follow::chase(&mut player, target_pos, target_size);
         }
 
         player_cd = player_cd.saturating_sub(1);
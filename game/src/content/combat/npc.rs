@@ -9,7 +9,9 @@ use super::{
 };
 use crate::{
     content::combat::ranged,
+    entity::HitType,
     npc::Npc,
+    player::Prayer,
     provider,
     world::{Position, World, can_interact_rect, find_path, has_line_of_sight},
 };
@@ -20,7 +22,7 @@ const HEAL_DELAY_TICKS: u16 = 50;
 pub struct NpcHit {
     pub target: CombatTarget,
     pub damage: u16,
-    pub hit_type: crate::entity::HitType,
+    pub hit_type: HitType,
     pub delay: u16,
     pub projectile: Option<Projectile>,
 }
@@ -66,6 +68,7 @@ pub fn npc_def(combat: &crate::npc::NpcCombat, atk_type: AttackType) -> DefenceR
             combat.def_ranged,
             combat.def_magic,
         ),
+        def_prayer: 1.0,
         stance: WeaponStance::Defensive,
     }
 }
@@ -77,6 +80,8 @@ pub fn npc_melee_atk(npc: &Npc) -> AttackRoll {
         str_level: c.str_level,
         atk_bonus: c.atk_bonus,
         str_bonus: c.str_bonus,
+        atk_prayer: 1.0,
+        str_prayer: 1.0,
         stance: WeaponStance::Accurate,
     }
 }
@@ -86,10 +91,21 @@ pub fn player_def(world: &World, target: CombatTarget, atk_type: AttackType) ->
     super::player::player_def(&world.player(idx), atk_type)
 }
 
+fn protected(world: &World, target: CombatTarget, atk_type: AttackType) -> bool {
+    let CombatTarget::Player(idx) = target else { return false };
+    let prayer = match atk_type {
+        AttackType::Ranged => Prayer::ProtectFromMissiles,
+        AttackType::Magic => Prayer::ProtectFromMagic,
+        _ => Prayer::ProtectFromMelee,
+    };
+    world.player(idx).prayer().is_active(prayer)
+}
+
 pub fn roll_npc_hit(npc: &Npc, target: CombatTarget, world: &World, atk_type: AttackType, delay: u16) -> NpcHit {
     let atk = npc_melee_atk(npc);
     let def = player_def(world, target, atk_type);
-    let (hit_type, damage) = roll_hit(&atk, &def, atk_type);
+    let (hit_type, damage) =
+        if protected(world, target, atk_type) { (HitType::Block, 0) } else { roll_hit(&atk, &def, atk_type) };
     NpcHit {
         target,
         damage,
@@ -138,6 +154,48 @@ pub fn follow_target(npc: &mut Npc, target_pos: Position, size: i32) {
     }
 }
 
+pub fn hunt(npc: &mut Npc, world: &World) {
+    let radius = npc.combat.aggro_radius as i32;
+    if radius == 0 || npc.combat_target.is_some() || npc.is_dying() {
+        return;
+    }
+    if world.npc_action_states.lock().contains_key(&npc.index) {
+        return;
+    }
+
+    let npc_level = provider::get_npc_type(npc.npc_id as u32).map_or(0, |t| t.combat_level.max(0) as u16);
+    let size = npc_size(npc.npc_id);
+    let center = npc_center(npc);
+    let collision = provider::get_collision();
+
+    let target = world
+        .players
+        .keys()
+        .into_iter()
+        .filter_map(|index| {
+            let player = world.player(index);
+            let pos = player.position;
+            let dist = ranged::distance_to_target(pos, npc.position, size);
+            let from_spawn = (pos.x - npc.spawn_position.x)
+                .abs()
+                .max((pos.y - npc.spawn_position.y).abs());
+            let eligible = pos.plane == npc.position.plane
+                && dist <= radius
+                && from_spawn <= MAX_CHASE_DISTANCE
+                && !player.hitpoints().is_dying()
+                && (npc.combat.always_aggressive || player.stat().combat_level() as u16 <= npc_level * 2)
+                && !player.tolerance().is_tolerated(npc.combat.tolerance_ticks)
+                && has_line_of_sight(collision, center, pos);
+            eligible.then_some((index, dist))
+        })
+        .min_by_key(|&(_, dist)| dist)
+        .map(|(index, _)| index);
+
+    if let Some(index) = target {
+        crate::npc::fire_action(npc, start_combat(index));
+    }
+}
+
 fn default_attack(npc: &mut Npc, target: CombatTarget, world: &World) -> NpcAttackResult {
     NpcAttackResult {
         seq: npc.combat.atk_seq,
@@ -1,8 +1,8 @@
 use filesystem::{AttackType, WeaponCategory, WeaponStance, XpType};
 
-use super::formula::{AttackRoll, DefenceRoll, atk_bonus_for_type, def_bonus_for_type};
+use super::formula::{AttackRoll, DefenceRoll, atk_bonus_for_type, def_bonus_for_type, magic_def_level};
 use crate::{
-    player::{Stat, active_player},
+    player::{Prayer, Stat, active_player},
     provider,
 };
 
@@ -59,6 +59,8 @@ pub fn melee_atk(player: &crate::player::Player) -> (AttackRoll, ResolvedStyle)
         str_level: player.stat().level(Stat::Strength) as u16,
         atk_bonus: atk_bonus_for_type(style.atk_type, bonuses.atk_stab, bonuses.atk_slash, bonuses.atk_crush),
         str_bonus: bonuses.str_bonus,
+        atk_prayer: player.prayer().multiplier(Prayer::attack_multiplier),
+        str_prayer: player.prayer().multiplier(Prayer::strength_multiplier),
         stance: style.stance,
     };
     (atk, style)
@@ -73,6 +75,8 @@ pub fn ranged_atk(player: &crate::player::Player) -> (AttackRoll, ResolvedStyle)
         str_level: ranged_level,
         atk_bonus: bonuses.atk_ranged,
         str_bonus: bonuses.ranged_str,
+        atk_prayer: player.prayer().multiplier(Prayer::ranged_multiplier),
+        str_prayer: player.prayer().multiplier(Prayer::ranged_multiplier),
         stance: style.stance,
     };
     (atk, style)
@@ -81,8 +85,13 @@ pub fn ranged_atk(player: &crate::player::Player) -> (AttackRoll, ResolvedStyle)
 pub fn player_def(player: &crate::player::Player, atk_type: AttackType) -> DefenceRoll {
     let bonuses = player.worn().bonuses();
     let style = resolve_style(player);
+    let defence = player.stat().level(Stat::Defence) as u16;
+    let def_level = match atk_type {
+        AttackType::Magic => magic_def_level(player.stat().level(Stat::Magic) as u16, defence),
+        _ => defence,
+    };
     DefenceRoll {
-        def_level: player.stat().level(Stat::Defence) as u16,
+        def_level,
         def_bonus: def_bonus_for_type(
             atk_type,
             bonuses.def_stab,
@@ -91,6 +100,7 @@ pub fn player_def(player: &crate::player::Player, atk_type: AttackType) -> Defen
             bonuses.def_ranged,
             bonuses.def_magic,
         ),
+        def_prayer: player.prayer().multiplier(Prayer::defence_multiplier),
         stance: style.stance,
     }
 }
@@ -108,13 +118,17 @@ pub async fn award_combat_xp(xp_type: XpType, damage: u16) {
             active_player().stat_mut().add_xp(Stat::Ranged, half).await;
             active_player().stat_mut().add_xp(Stat::Defence, half).await;
         }
+        XpType::Magic => player.stat_mut().add_xp(Stat::Magic, damage as f64 * 2.0).await,
+        XpType::MagicAndDefence => {
+            player.stat_mut().add_xp(Stat::Magic, damage as f64 * 1.33).await;
+            player.stat_mut().add_xp(Stat::Defence, damage as f64).await;
+        }
         XpType::Shared => {
             let third = base / 3.0;
             player.stat_mut().add_xp(Stat::Attack, third).await;
             player.stat_mut().add_xp(Stat::Strength, third).await;
             player.stat_mut().add_xp(Stat::Defence, third).await;
         }
-        _ => {}
     }
     active_player()
         .stat_mut()
@@ -21,7 +21,7 @@ struct ProjectileTemplate {
     curve: u8,
 }
 
-fn eucl_dist(src: Position, dst: Position) -> i32 {
+pub fn eucl_dist(src: Position, dst: Position) -> i32 {
     let dx = (dst.x - src.x) as f64;
     let dy = (dst.y - src.y) as f64;
     (dx * dx + dy * dy).sqrt() as i32
@@ -32,7 +32,7 @@ pub fn flight_time(speed: u16, dist: i32) -> u16 {
     dist as u16 * 30 / divisor
 }
 
-fn center_of(pos: Position, size: i32) -> Position {
+pub fn center_of(pos: Position, size: i32) -> Position {
     Position::new(pos.x + size / 2, pos.y + size / 2, pos.plane)
 }
 
@@ -0,0 +1,56 @@
+use net::{InvEntry, InvType};
+
+use super::items_on_death;
+use crate::player::{InterfaceSlot, Obj};
+
+const KEPT_ON_DEATH: u16 = 102;
+const KEPT_COMPONENT: u16 = 18;
+const LOST_COMPONENT: u16 = 21;
+const INFO_COMPONENT: u16 = 23;
+const KEPT_INV: u16 = 584;
+const LOST_INV: u16 = 585;
+
+fn entries(objs: &[Obj]) -> Vec<Option<InvEntry>> {
+    objs.iter()
+        .map(|obj| {
+            Some(InvEntry {
+                obj_id: obj.id,
+                amount: obj.amount,
+            })
+        })
+        .collect()
+}
+
+#[macros::on_interface(op = 1, interface = 387, component = 45)]
+async fn open_kept_on_death() {
+    let items = items_on_death(&player);
+    let safe = player.world().in_safe_zone(player.position);
+    let lost = if safe { &[][..] } else { &items.lost[..] };
+
+    player
+        .interface_mut()
+        .open_slot(InterfaceSlot::Modal, KEPT_ON_DEATH)
+        .await;
+
+    player
+        .set_items_options(KEPT_ON_DEATH, KEPT_COMPONENT, KEPT_INV, 4, 1, &[])
+        .await;
+    player
+        .set_items_options(KEPT_ON_DEATH, LOST_COMPONENT, LOST_INV, 8, 5, &[])
+        .await;
+    player
+        .update_inv(InvType::Custom(KEPT_INV), false, entries(&items.kept))
+        .await;
+    player.update_inv(InvType::Custom(LOST_INV), false, entries(lost)).await;
+
+    let info = if safe {
+        "You are in a safe area. You will not lose any items if you die here.".to_string()
+    } else {
+        format!(
+            "You will keep {} item{} if you die. The rest will be dropped.",
+            items.kept.len(),
+            if items.kept.len() == 1 { "" } else { "s" }
+        )
+    };
+    player.if_set_text(KEPT_ON_DEATH, INFO_COMPONENT, info).await;
+}
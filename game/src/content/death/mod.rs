@@ -0,0 +1,99 @@
+mod interface;
+
+use std::cmp::Reverse;
+
+use filesystem::WearPos;
+use num_enum::TryFromPrimitive;
+
+use crate::{
+    player::{Attacker, INV_SIZE, Obj, Player, Prayer, WORN_SIZE},
+    provider,
+};
+
+const BASE_KEPT: usize = 3;
+const BONES: u16 = 526;
+
+pub struct DeathItems {
+    pub kept: Vec<Obj>,
+    pub lost: Vec<Obj>,
+}
+
+fn keep_count(player: &Player) -> usize {
+    BASE_KEPT + player.prayer().is_active(Prayer::ProtectItem) as usize
+}
+
+fn carried(player: &Player) -> impl Iterator<Item = Obj> + '_ {
+    let inv = (0..INV_SIZE).filter_map(|i| player.inv().slot(i));
+    let worn = (0..WORN_SIZE)
+        .filter_map(|i| WearPos::try_from_primitive(i).ok())
+        .filter_map(|pos| player.worn().slot(pos));
+    inv.chain(worn)
+}
+
+pub fn items_on_death(player: &Player) -> DeathItems {
+    let mut lost = Vec::new();
+    let mut units = Vec::new();
+    for obj in carried(player) {
+        let stackable = provider::get_obj_type(obj.id as u32).is_some_and(|t| t.stackable);
+        if stackable {
+            lost.push(obj);
+        } else {
+            let value = provider::get_obj_value(obj.id as u32);
+            units.extend((0..obj.amount).map(|_| (obj.id, value)));
+        }
+    }
+
+    units.sort_by_key(|&(_, value)| Reverse(value));
+    let keep = keep_count(player).min(units.len());
+    let kept = units[..keep].iter().map(|&(id, _)| Obj::new(id, 1)).collect();
+    lost.extend(units[keep..].iter().map(|&(id, _)| Obj::new(id, 1)));
+
+    DeathItems { kept, lost }
+}
+
+pub async fn drop_items_on_death(player: &mut Player, killer: Option<Attacker>) {
+    let world = player.world();
+    let position = player.position;
+    if world.in_safe_zone(position) {
+        return;
+    }
+
+    let killer = killer
+        .filter(|a| a.index != player.index && world.players.contains(a.index))
+        .filter(|a| world.player(a.index).account_id == a.account_id)
+        .map(|a| a.index);
+    let owner = killer.unwrap_or(player.index);
+    let pvp = killer.is_some();
+
+    let items = items_on_death(player);
+    for obj in &items.lost {
+        world
+            .obj_stacks
+            .add_death_drop(obj.id, obj.amount, position, owner, pvp);
+    }
+    world.obj_stacks.add_death_drop(BONES, 1, position, owner, pvp);
+    drop(world);
+
+    player.inv_mut().clear().await;
+    for pos in (0..WORN_SIZE).filter_map(|i| WearPos::try_from_primitive(i).ok()) {
+        player.worn_mut().set(pos, None);
+    }
+    for obj in &items.kept {
+        player.inv_mut().add(obj.id, obj.amount).await;
+    }
+
+    player.worn_mut().flush().await;
+    player.appearance_mut().flush();
+    player.combat_mut().set_combat_style(0);
+    player.varp_mut().send_varp(43, 0).await;
+    player.combat_mut().set_autocast(None).await;
+
+    if let Some(index) = killer {
+        tracing::info!(
+            victim = player.username,
+            killer = player.world().player(index).username,
+            lost = items.lost.len(),
+            "Player killed"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    player::{FaceEntityMask, Player},
+    world::{Position, find_path, find_path_adjacent_rect},
+};
+
+const TELEPORT_THRESHOLD: i32 = 2;
+
+pub fn chase(player: &mut Player, target_pos: Position, size: i32) {
+    player.entity.walk_queue = find_path_adjacent_rect(player.position, target_pos, size, size, 0);
+}
+
+/// The tile directly behind a target that moved from `from` to `to`.
+fn behind(from: Position, to: Position) -> Position {
+    Position::new(
+        to.x + (from.x - to.x).signum(),
+        to.y + (from.y - to.y).signum(),
+        to.plane,
+    )
+}
+
+pub fn lost_target(player: &Player, last_pos: Option<Position>, target_pos: Position) -> bool {
+    let teleported = last_pos.is_some_and(|p| {
+        p.plane != target_pos.plane
+            || (p.x - target_pos.x).abs() > TELEPORT_THRESHOLD
+            || (p.y - target_pos.y).abs() > TELEPORT_THRESHOLD
+    });
+    teleported || !player.viewport.is_within_view(player.position, target_pos)
+}
+
+#[macros::player_action]
+pub async fn follow(target_index: usize) {
+    let face = target_index as u16 + 32768;
+    player.entity.face_target = Some(face);
+    player.player_info.add_mask(FaceEntityMask(face));
+
+    let mut last_pos: Option<Position> = None;
+    let mut trail: Option<Position> = None;
+    loop {
+        let world = player.world();
+        if !world.players.contains(target_index) {
+            break;
+        }
+
+        let (target_pos, logging_out) = {
+            let target = world.player(target_index);
+            (target.position, target.logging_out)
+        };
+        drop(world);
+
+        if logging_out || lost_target(&player, last_pos, target_pos) {
+            break;
+        }
+        if let Some(last) = last_pos.filter(|&p| p != target_pos) {
+            trail = Some(behind(last, target_pos));
+        }
+        last_pos = Some(target_pos);
+
+        match trail {
+            Some(tile) => player.entity.walk_queue = find_path(player.position, tile),
+            None => chase(&mut player, target_pos, 1),
+        }
+        delay!(1);
+    }
+
+    player.entity.stop();
+    player.entity.face_target = None;
+    player.player_info.add_mask(FaceEntityMask(65535));
+}
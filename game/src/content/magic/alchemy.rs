@@ -0,0 +1,75 @@
+use super::runes::{FIRE, NATURE, Runes, check_runes, remove_runes};
+use crate::provider;
+
+const COINS: u16 = 995;
+const ALCH_DELAY: u16 = 3;
+
+pub struct AlchemySpell {
+    pub component: u16,
+    level: u8,
+    xp: f64,
+    runes: Runes,
+    value_ratio: f64,
+    seq: u16,
+    spotanim: u16,
+}
+
+const LOW_ALCHEMY: AlchemySpell = AlchemySpell {
+    component: 38,
+    level: 21,
+    xp: 31.0,
+    runes: &[(FIRE, 3), (NATURE, 1)],
+    value_ratio: 0.4,
+    seq: 712,
+    spotanim: 112,
+};
+
+const HIGH_ALCHEMY: AlchemySpell = AlchemySpell {
+    component: 59,
+    level: 55,
+    xp: 65.0,
+    runes: &[(FIRE, 5), (NATURE, 1)],
+    value_ratio: 0.6,
+    seq: 713,
+    spotanim: 113,
+};
+
+pub const ALCHEMY_SPELLS: &[AlchemySpell] = &[LOW_ALCHEMY, HIGH_ALCHEMY];
+
+pub fn alchemy_spell(component: u16) -> Option<&'static AlchemySpell> {
+    ALCHEMY_SPELLS.iter().find(|s| s.component == component)
+}
+
+#[macros::player_action]
+pub async fn alchemy(spell: &'static AlchemySpell, slot: u16) {
+    let Some(obj) = player.inv().slot(slot as usize) else { return };
+    if obj.id == COINS {
+        send_message!("Coins are already made of gold.");
+        return;
+    }
+
+    requires!(stat = Magic, level = spell.level);
+    if !check_runes(&mut player, spell.runes).await {
+        return;
+    }
+
+    if obj.amount > 1 && player.inv().count(COINS) == 0 && player.inv().free_slots() == 0 {
+        send_message!("You don't have enough inventory space.");
+        return;
+    }
+
+    let coins = (provider::get_obj_value(obj.id as u32).max(0) as f64 * spell.value_ratio) as u32;
+
+    lock!();
+    player.entity.stop();
+    remove_runes(&mut player, spell.runes).await;
+    player.inv_mut().remove_obj(slot as usize, 1).await;
+    if coins > 0 {
+        player.inv_mut().add(COINS, coins).await;
+    }
+    seq!(spell.seq);
+    spotanim!(spell.spotanim, height = 100);
+    give_xp!(stat = Magic, amount = spell.xp);
+    delay!(ALCH_DELAY);
+    unlock!();
+}
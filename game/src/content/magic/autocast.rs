@@ -0,0 +1,24 @@
+use filesystem::WeaponCategory;
+
+use super::combat_spell;
+
+const SPELL_STYLE: u8 = 3;
+
+#[macros::on_interface(op = 2, interface = 192)]
+async fn select_autocast() {
+    let Some(spell) = combat_spell(component) else { return };
+
+    let staff = matches!(
+        player.worn().weapon_category(),
+        Some(WeaponCategory::Staff | WeaponCategory::BladedStaff)
+    );
+    if !staff {
+        send_message!("You can only autocast spells while wielding a staff.");
+        return;
+    }
+
+    requires!(stat = Magic, level = spell.level);
+    player.combat_mut().set_combat_style(SPELL_STYLE);
+    player.varp_mut().send_varp(43, SPELL_STYLE as i32).await;
+    player.combat_mut().set_autocast(Some(spell)).await;
+}
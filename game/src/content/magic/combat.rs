@@ -0,0 +1,43 @@
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+use super::runes::{AIR, BLOOD, CHAOS, DEATH, EARTH, FIRE, MIND, Runes, WATER};
+
+pub struct CombatSpell {
+    pub name: &'static str,
+    pub component: u16,
+    pub autocast: i32,
+    pub level: u8,
+    pub xp: f64,
+    pub max_hit: u16,
+    pub runes: Runes,
+    pub seq: u16,
+    pub cast_spotanim: u16,
+    pub proj_spotanim: u16,
+    pub hit_spotanim: u16,
+}
+
+const BOLT_SEQ: u16 = 711;
+const WAVE_SEQ: u16 = 727;
+
+pub const SPELLS: &[CombatSpell] = &[
+    CombatSpell { name: "Wind Strike", component: 25, autocast: 3, level: 1, xp: 5.5, max_hit: 2, runes: &[(AIR, 1), (MIND, 1)], seq: BOLT_SEQ, cast_spotanim: 90, proj_spotanim: 91, hit_spotanim: 92 },
+    CombatSpell { name: "Water Strike", component: 28, autocast: 5, level: 5, xp: 7.5, max_hit: 4, runes: &[(WATER, 1), (AIR, 1), (MIND, 1)], seq: BOLT_SEQ, cast_spotanim: 93, proj_spotanim: 94, hit_spotanim: 95 },
+    CombatSpell { name: "Earth Strike", component: 30, autocast: 7, level: 9, xp: 9.5, max_hit: 6, runes: &[(EARTH, 2), (AIR, 1), (MIND, 1)], seq: BOLT_SEQ, cast_spotanim: 96, proj_spotanim: 97, hit_spotanim: 98 },
+    CombatSpell { name: "Fire Strike", component: 32, autocast: 9, level: 13, xp: 11.5, max_hit: 8, runes: &[(FIRE, 3), (AIR, 2), (MIND, 1)], seq: BOLT_SEQ, cast_spotanim: 99, proj_spotanim: 100, hit_spotanim: 101 },
+    CombatSpell { name: "Wind Bolt", component: 34, autocast: 11, level: 17, xp: 13.5, max_hit: 9, runes: &[(AIR, 2), (CHAOS, 1)], seq: BOLT_SEQ, cast_spotanim: 117, proj_spotanim: 118, hit_spotanim: 119 },
+    CombatSpell { name: "Water Bolt", component: 39, autocast: 13, level: 23, xp: 16.5, max_hit: 10, runes: &[(WATER, 2), (AIR, 2), (CHAOS, 1)], seq: BOLT_SEQ, cast_spotanim: 120, proj_spotanim: 121, hit_spotanim: 122 },
+    CombatSpell { name: "Earth Bolt", component: 42, autocast: 15, level: 29, xp: 19.5, max_hit: 11, runes: &[(EARTH, 3), (AIR, 2), (CHAOS, 1)], seq: BOLT_SEQ, cast_spotanim: 123, proj_spotanim: 124, hit_spotanim: 125 },
+    CombatSpell { name: "Fire Bolt", component: 45, autocast: 17, level: 35, xp: 22.5, max_hit: 12, runes: &[(FIRE, 4), (AIR, 3), (CHAOS, 1)], seq: BOLT_SEQ, cast_spotanim: 126, proj_spotanim: 127, hit_spotanim: 128 },
+    CombatSpell { name: "Wind Blast", component: 49, autocast: 19, level: 41, xp: 25.5, max_hit: 13, runes: &[(AIR, 3), (DEATH, 1)], seq: BOLT_SEQ, cast_spotanim: 132, proj_spotanim: 133, hit_spotanim: 134 },
+    CombatSpell { name: "Water Blast", component: 52, autocast: 21, level: 47, xp: 28.5, max_hit: 14, runes: &[(WATER, 3), (AIR, 3), (DEATH, 1)], seq: BOLT_SEQ, cast_spotanim: 135, proj_spotanim: 136, hit_spotanim: 137 },
+    CombatSpell { name: "Earth Blast", component: 58, autocast: 23, level: 53, xp: 31.5, max_hit: 15, runes: &[(EARTH, 4), (AIR, 3), (DEATH, 1)], seq: BOLT_SEQ, cast_spotanim: 138, proj_spotanim: 139, hit_spotanim: 140 },
+    CombatSpell { name: "Fire Blast", component: 63, autocast: 25, level: 59, xp: 34.5, max_hit: 16, runes: &[(FIRE, 5), (AIR, 4), (DEATH, 1)], seq: BOLT_SEQ, cast_spotanim: 129, proj_spotanim: 130, hit_spotanim: 131 },
+    CombatSpell { name: "Wind Wave", component: 70, autocast: 27, level: 62, xp: 36.0, max_hit: 17, runes: &[(AIR, 5), (BLOOD, 1)], seq: WAVE_SEQ, cast_spotanim: 158, proj_spotanim: 159, hit_spotanim: 160 },
+    CombatSpell { name: "Water Wave", component: 73, autocast: 29, level: 65, xp: 37.5, max_hit: 18, runes: &[(WATER, 7), (AIR, 5), (BLOOD, 1)], seq: WAVE_SEQ, cast_spotanim: 161, proj_spotanim: 162, hit_spotanim: 163 },
+    CombatSpell { name: "Earth Wave", component: 77, autocast: 31, level: 70, xp: 40.0, max_hit: 19, runes: &[(EARTH, 7), (AIR, 5), (BLOOD, 1)], seq: WAVE_SEQ, cast_spotanim: 164, proj_spotanim: 165, hit_spotanim: 166 },
+    CombatSpell { name: "Fire Wave", component: 80, autocast: 33, level: 75, xp: 42.5, max_hit: 20, runes: &[(FIRE, 7), (AIR, 5), (BLOOD, 1)], seq: WAVE_SEQ, cast_spotanim: 155, proj_spotanim: 156, hit_spotanim: 157 },
+];
+
+pub fn combat_spell(component: u16) -> Option<&'static CombatSpell> {
+    SPELLS.iter().find(|s| s.component == component)
+}
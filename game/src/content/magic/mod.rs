@@ -0,0 +1,9 @@
+mod alchemy;
+mod autocast;
+mod combat;
+mod runes;
+mod teleport;
+
+pub use alchemy::{ALCHEMY_SPELLS, alchemy, alchemy_spell};
+pub use combat::{CombatSpell, SPELLS, combat_spell};
+pub use runes::{check_runes, remove_runes};
@@ -0,0 +1,88 @@
+use filesystem::WearPos;
+
+use crate::player::Player;
+
+pub const FIRE: u16 = 554;
+pub const WATER: u16 = 555;
+pub const AIR: u16 = 556;
+pub const EARTH: u16 = 557;
+pub const MIND: u16 = 558;
+pub const BODY: u16 = 559;
+pub const DEATH: u16 = 560;
+pub const NATURE: u16 = 561;
+pub const CHAOS: u16 = 562;
+pub const LAW: u16 = 563;
+pub const COSMIC: u16 = 564;
+pub const BLOOD: u16 = 565;
+pub const SOUL: u16 = 566;
+
+pub type Runes = &'static [(u16, u32)];
+
+struct Staff {
+    id: u16,
+    runes: &'static [u16],
+}
+
+#[rustfmt::skip]
+const STAVES: &[Staff] = &[
+    Staff { id: 1381, runes: &[AIR] },
+    Staff { id: 1383, runes: &[WATER] },
+    Staff { id: 1385, runes: &[EARTH] },
+    Staff { id: 1387, runes: &[FIRE] },
+    Staff { id: 1397, runes: &[AIR] },
+    Staff { id: 1395, runes: &[WATER] },
+    Staff { id: 1399, runes: &[EARTH] },
+    Staff { id: 1393, runes: &[FIRE] },
+    Staff { id: 1405, runes: &[AIR] },
+    Staff { id: 1403, runes: &[WATER] },
+    Staff { id: 1407, runes: &[EARTH] },
+    Staff { id: 1401, runes: &[FIRE] },
+    Staff { id: 3053, runes: &[FIRE, EARTH] },
+    Staff { id: 3054, runes: &[FIRE, EARTH] },
+    Staff { id: 6562, runes: &[WATER, EARTH] },
+    Staff { id: 6563, runes: &[WATER, EARTH] },
+    Staff { id: 11736, runes: &[WATER, FIRE] },
+    Staff { id: 11738, runes: &[WATER, FIRE] },
+    Staff { id: 11998, runes: &[AIR, FIRE] },
+    Staff { id: 12000, runes: &[AIR, FIRE] },
+];
+
+fn staff_runes(player: &Player) -> &'static [u16] {
+    let Some(weapon) = player.worn().slot(WearPos::Weapon) else { return &[] };
+    STAVES
+        .iter()
+        .find(|s| s.id == weapon.id)
+        .map(|s| s.runes)
+        .unwrap_or(&[])
+}
+
+fn required(player: &Player, runes: Runes) -> impl Iterator<Item = (u16, u32)> {
+    let provided = staff_runes(player);
+    runes.iter().copied().filter(move |(id, _)| !provided.contains(id))
+}
+
+pub fn missing_rune(player: &Player, runes: Runes) -> Option<u16> {
+    required(player, runes)
+        .find(|&(id, amount)| player.inv().count(id) < amount)
+        .map(|(id, _)| id)
+}
+
+pub async fn remove_runes(player: &mut Player, runes: Runes) {
+    let required: Vec<_> = required(player, runes).collect();
+    for (id, amount) in required {
+        player.inv_mut().remove(id, amount).await;
+    }
+}
+
+pub async fn check_runes(player: &mut Player, runes: Runes) -> bool {
+    use crate::player::Clientbound;
+
+    let Some(id) = missing_rune(player, runes) else { return true };
+    let name = crate::provider::get_obj_type(id as u32)
+        .map(|t| t.name.as_str())
+        .unwrap_or("runes");
+    player
+        .send_message(format!("You do not have enough {}s to cast this spell.", name))
+        .await;
+    false
+}
@@ -0,0 +1,77 @@
+use super::runes::{AIR, EARTH, FIRE, LAW, WATER, check_runes, remove_runes};
+use crate::world::Position;
+
+const TELEPORT_SEQ: u16 = 714;
+const TELEPORT_SPOTANIM: u16 = 308;
+const TELEPORT_DELAY: u16 = 3;
+
+const HOME_SEQ: u16 = 4847;
+const HOME_SPOTANIM: u16 = 800;
+const HOME_DELAY: u16 = 16;
+
+#[macros::on_interface(op = 1, interface = 192, component = 24)]
+async fn home_teleport() {
+    lock!();
+    player.entity.stop();
+    seq!(HOME_SEQ);
+    spotanim!(HOME_SPOTANIM);
+    delay!(HOME_DELAY);
+    player.movement_mut().teleport(Position::new(3222, 3218, 0)).await;
+    seq!(0xFFFF);
+    unlock!();
+}
+
+macro_rules! teleport {
+    ($fn_name:ident, $component:expr, $level:expr, $xp:expr, $runes:expr, $x:expr, $y:expr) => {
+        #[macros::on_interface(op = 1, interface = 192, component = $component)]
+        async fn $fn_name() {
+            requires!(stat = Magic, level = $level);
+            if !check_runes(&mut player, $runes).await {
+                return;
+            }
+
+            lock!();
+            player.entity.stop();
+            remove_runes(&mut player, $runes).await;
+            seq!(TELEPORT_SEQ);
+            spotanim!(TELEPORT_SPOTANIM, height = 100);
+            delay!(TELEPORT_DELAY);
+            player.movement_mut().teleport(Position::new($x, $y, 0)).await;
+            seq!(0xFFFF);
+            give_xp!(stat = Magic, amount = $xp);
+            unlock!();
+        }
+    };
+}
+
+teleport!(
+    varrock_teleport,
+    40,
+    25,
+    35.0,
+    &[(LAW, 1), (AIR, 3), (FIRE, 1)],
+    3212,
+    3424
+);
+teleport!(
+    lumbridge_teleport,
+    43,
+    31,
+    41.0,
+    &[(LAW, 1), (AIR, 3), (EARTH, 1)],
+    3222,
+    3218
+);
+teleport!(
+    falador_teleport,
+    46,
+    37,
+    48.0,
+    &[(LAW, 1), (AIR, 3), (WATER, 1)],
+    2964,
+    3378
+);
+teleport!(camelot_teleport, 51, 45, 55.5, &[(LAW, 1), (AIR, 5)], 2757, 3477);
+teleport!(ardougne_teleport, 57, 51, 61.0, &[(LAW, 2), (WATER, 2)], 2661, 3300);
+teleport!(watchtower_teleport, 62, 58, 68.0, &[(LAW, 2), (EARTH, 2)], 2549, 3112);
+teleport!(trollheim_teleport, 69, 61, 68.0, &[(LAW, 2), (FIRE, 2)], 2891, 3678);
@@ -1,11 +1,25 @@
 mod combat;
 pub use combat::{
     AttackRoll, CombatTarget, NpcAttackResult, NpcCombatScript, NpcHit, PendingHit, Projectile, accuracy, get_spec,
-    max_hit, melee_atk, npc_center, npc_melee_atk, npc_size, player_def, process_pending_hits, roll_npc_hit,
+    hunt, max_hit, melee_atk, npc_center, npc_melee_atk, npc_size, player_def, process_pending_hits, roll_npc_hit,
     send_projectile, start_combat,
 };
 mod bank;
+mod clan;
+mod death;
+mod follow;
+mod magic;
 mod npc;
 mod obj;
 mod skill;
+mod trade;
 mod ui;
+
+pub use clan::{
+    ban_from_clan, clan_logout, join_clan, kick_clan_member, leave_clan, rejoin_clan, send_clan_message, set_clan_rank,
+    sync_clan_friend, unban_from_clan,
+};
+pub use death::drop_items_on_death;
+pub use follow::follow;
+pub use magic::{ALCHEMY_SPELLS, CombatSpell, SPELLS, alchemy, alchemy_spell, check_runes, combat_spell, remove_runes};
+pub use trade::{close_trade_interfaces, decline_trade};
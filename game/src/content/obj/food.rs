@@ -1,21 +1,66 @@
+use crate::player::{Clientbound, Obj, Player};
+
 const EAT_SEQ: u16 = 829;
 const EAT_DELAY: u16 = 3;
+const COMBO_ATK_DELAY: u16 = 2;
+
+struct Food {
+    heal: u8,
+    name: &'static str,
+    next: Option<u16>,
+    combo: bool,
+}
 
-async fn eat(player: &mut crate::player::Player, slot: u16, heal: u8, name: &str) {
-    use crate::player::Clientbound;
+async fn eat(player: &mut Player, slot: u16, food: Food) {
+    if food.combo {
+        if !player.consumable().can_eat_combo() {
+            return;
+        }
+        player.consumable_mut().set_combo_delay(EAT_DELAY);
+        player.consumable_mut().set_potion_delay(EAT_DELAY);
+        player.combat_mut().set_eat_delay(COMBO_ATK_DELAY);
+    } else {
+        if !player.consumable().can_eat() {
+            return;
+        }
+        player.combat_mut().set_eat_delay(EAT_DELAY);
+    }
+    player.consumable_mut().set_food_delay(EAT_DELAY);
 
-    player.inv_mut().clear_slot(slot as usize).await;
-    player.hitpoints_mut().heal(heal);
+    match food.next {
+        Some(next) => player.inv_mut().set(slot as usize, Some(Obj::new(next, 1))).await,
+        None => player.inv_mut().clear_slot(slot as usize).await,
+    }
+    player.hitpoints_mut().heal(food.heal);
     player.seq(EAT_SEQ);
-    player.combat_mut().set_eat_delay(EAT_DELAY);
-    player.send_message(format!("You eat the {}.", name)).await;
+
+    let message = match food.next {
+        Some(_) => format!("You eat some of the {}.", food.name),
+        None => format!("You eat the {}.", food.name),
+    };
+    player.send_message(message).await;
 }
 
 macro_rules! food {
     ($fn_name:ident, $obj_id:expr, $heal:expr, $name:expr) => {
+        food!(@handler $fn_name, $obj_id, $heal, $name, None, false);
+    };
+    ($fn_name:ident, $obj_id:expr, $heal:expr, $name:expr, next = $next:expr) => {
+        food!(@handler $fn_name, $obj_id, $heal, $name, Some($next), false);
+    };
+    ($fn_name:ident, $obj_id:expr, $heal:expr, $name:expr, combo) => {
+        food!(@handler $fn_name, $obj_id, $heal, $name, None, true);
+    };
+    (@handler $fn_name:ident, $obj_id:expr, $heal:expr, $name:expr, $next:expr, $combo:expr) => {
         #[macros::on_obj(id = $obj_id, op = Op1)]
         async fn $fn_name() {
-            eat(&mut player, slot, $heal, $name).await;
+            let food = Food {
+                heal: $heal,
+                name: $name,
+                next: $next,
+                combo: $combo,
+            };
+            eat(&mut player, slot, food).await;
         }
     };
 }
@@ -38,10 +83,27 @@ food!(eat_manta_ray, 391, 22, "manta ray");
 food!(eat_sea_turtle, 397, 21, "sea turtle");
 food!(eat_dark_crab, 11936, 22, "dark crab");
 food!(eat_bread, 2309, 5, "bread");
-food!(eat_cake, 1891, 4, "cake");
-food!(eat_2_3_cake, 1893, 4, "cake");
+food!(eat_cake, 1891, 4, "cake", next = 1893);
+food!(eat_2_3_cake, 1893, 4, "cake", next = 1895);
 food!(eat_slice_cake, 1895, 4, "cake");
+food!(eat_chocolate_cake, 1897, 5, "chocolate cake", next = 1899);
+food!(eat_2_3_chocolate_cake, 1899, 5, "chocolate cake", next = 1901);
+food!(eat_chocolate_slice, 1901, 5, "chocolate cake");
+food!(eat_plain_pizza, 2289, 7, "plain pizza", next = 2291);
+food!(eat_half_plain_pizza, 2291, 7, "plain pizza");
+food!(eat_meat_pizza, 2293, 8, "meat pizza", next = 2295);
+food!(eat_half_meat_pizza, 2295, 8, "meat pizza");
+food!(eat_anchovy_pizza, 2297, 9, "anchovy pizza", next = 2299);
+food!(eat_half_anchovy_pizza, 2299, 9, "anchovy pizza");
+food!(eat_pineapple_pizza, 2301, 11, "pineapple pizza", next = 2303);
+food!(eat_half_pineapple_pizza, 2303, 11, "pineapple pizza");
+food!(eat_redberry_pie, 2325, 5, "redberry pie", next = 2333);
+food!(eat_half_redberry_pie, 2333, 5, "redberry pie", next = 2313);
+food!(eat_meat_pie, 2327, 6, "meat pie", next = 2331);
+food!(eat_half_meat_pie, 2331, 6, "meat pie", next = 2313);
+food!(eat_apple_pie, 2323, 7, "apple pie", next = 2335);
+food!(eat_half_apple_pie, 2335, 7, "apple pie", next = 2313);
 food!(eat_cooked_chicken, 2140, 3, "chicken");
 food!(eat_cooked_meat, 2142, 3, "meat");
 food!(eat_potato_cheese, 6705, 16, "potato with cheese");
-food!(eat_karambwan, 3144, 18, "karambwan");
+food!(eat_karambwan, 3144, 18, "karambwan", combo);
@@ -1,3 +1,4 @@
 mod drop;
 mod food;
+mod potion;
 mod wear;
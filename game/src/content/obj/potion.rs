@@ -0,0 +1,234 @@
+use crate::player::{Clientbound, Obj, Player, Stat};
+
+const DRINK_SEQ: u16 = 829;
+const DRINK_DELAY: u16 = 3;
+const VIAL: u16 = 229;
+
+#[derive(Clone, Copy)]
+enum Effect {
+    Boost(Stat, u8, f64),
+    Restore(Stat, u8, f64),
+    Prayer(u8, f64),
+    Energy(u16),
+}
+
+struct Potion {
+    name: &'static str,
+    doses: &'static [u16],
+    effects: &'static [Effect],
+}
+
+fn scaled(base: u8, flat: u8, percent: f64) -> u8 {
+    flat.saturating_add((base as f64 * percent) as u8)
+}
+
+async fn apply(player: &mut Player, effect: Effect) {
+    match effect {
+        Effect::Boost(stat, flat, percent) => {
+            let amount = scaled(player.stat().base_level(stat), flat, percent);
+            player.stat_mut().boost(stat, amount).await;
+        }
+        Effect::Restore(stat, flat, percent) => {
+            let amount = scaled(player.stat().base_level(stat), flat, percent);
+            player.stat_mut().restore(stat, amount).await;
+        }
+        Effect::Prayer(flat, percent) => {
+            let amount = scaled(player.stat().base_level(Stat::Prayer), flat, percent);
+            player.prayer_mut().restore(amount);
+        }
+        Effect::Energy(amount) => player.movement_mut().add_run_energy(amount).await,
+    }
+}
+
+async fn drink(player: &mut Player, slot: u16, potion: &Potion) {
+    if !player.consumable().can_drink() {
+        return;
+    }
+    let Some(obj) = player.inv().slot(slot as usize) else { return };
+    let Some(dose) = potion.doses.iter().position(|&id| id == obj.id) else { return };
+
+    player.consumable_mut().set_potion_delay(DRINK_DELAY);
+    player.consumable_mut().set_food_delay(DRINK_DELAY);
+
+    let next = potion.doses.get(dose + 1).copied().unwrap_or(VIAL);
+    player.inv_mut().set(slot as usize, Some(Obj::new(next, 1))).await;
+    player.seq(DRINK_SEQ);
+
+    for &effect in potion.effects {
+        apply(player, effect).await;
+    }
+
+    player
+        .send_message(format!("You drink some of your {}.", potion.name))
+        .await;
+    let message = match potion.doses.len() - dose - 1 {
+        0 => "You have finished your potion.".to_string(),
+        1 => "You have 1 dose of potion left.".to_string(),
+        left => format!("You have {} doses of potion left.", left),
+    };
+    player.send_message(message).await;
+}
+
+macro_rules! potion {
+    ($name:expr, [$($effect:expr),+ $(,)?], $($fn_name:ident = $obj_id:expr),+ $(,)?) => {
+        const _: () = {
+            const POTION: Potion = Potion {
+                name: $name,
+                doses: &[$($obj_id),+],
+                effects: &[$($effect),+],
+            };
+
+            $(
+                #[macros::on_obj(id = $obj_id, op = Op1)]
+                async fn $fn_name() {
+                    drink(&mut player, slot, &POTION).await;
+                }
+            )+
+        };
+    };
+}
+
+use Effect::{Boost, Energy, Prayer, Restore};
+
+potion!(
+    "Attack potion",
+    [Boost(Stat::Attack, 3, 0.10)],
+    drink_attack_4 = 2428,
+    drink_attack_3 = 121,
+    drink_attack_2 = 123,
+    drink_attack_1 = 125
+);
+potion!(
+    "Strength potion",
+    [Boost(Stat::Strength, 3, 0.10)],
+    drink_strength_4 = 113,
+    drink_strength_3 = 115,
+    drink_strength_2 = 117,
+    drink_strength_1 = 119
+);
+potion!(
+    "Defence potion",
+    [Boost(Stat::Defence, 3, 0.10)],
+    drink_defence_4 = 2432,
+    drink_defence_3 = 133,
+    drink_defence_2 = 135,
+    drink_defence_1 = 137
+);
+potion!(
+    "Super attack",
+    [Boost(Stat::Attack, 5, 0.15)],
+    drink_super_attack_4 = 2436,
+    drink_super_attack_3 = 145,
+    drink_super_attack_2 = 147,
+    drink_super_attack_1 = 149
+);
+potion!(
+    "Super strength",
+    [Boost(Stat::Strength, 5, 0.15)],
+    drink_super_strength_4 = 2440,
+    drink_super_strength_3 = 157,
+    drink_super_strength_2 = 159,
+    drink_super_strength_1 = 161
+);
+potion!(
+    "Super defence",
+    [Boost(Stat::Defence, 5, 0.15)],
+    drink_super_defence_4 = 2442,
+    drink_super_defence_3 = 163,
+    drink_super_defence_2 = 165,
+    drink_super_defence_1 = 167
+);
+potion!(
+    "Combat potion",
+    [Boost(Stat::Attack, 3, 0.10), Boost(Stat::Strength, 3, 0.10)],
+    drink_combat_4 = 9739,
+    drink_combat_3 = 9741,
+    drink_combat_2 = 9743,
+    drink_combat_1 = 9745
+);
+potion!(
+    "Ranging potion",
+    [Boost(Stat::Ranged, 4, 0.10)],
+    drink_ranging_4 = 2444,
+    drink_ranging_3 = 169,
+    drink_ranging_2 = 171,
+    drink_ranging_1 = 173
+);
+potion!(
+    "Magic potion",
+    [Boost(Stat::Magic, 4, 0.0)],
+    drink_magic_4 = 3040,
+    drink_magic_3 = 3042,
+    drink_magic_2 = 3044,
+    drink_magic_1 = 3046
+);
+potion!(
+    "Prayer potion",
+    [Prayer(7, 0.25)],
+    drink_prayer_4 = 2434,
+    drink_prayer_3 = 139,
+    drink_prayer_2 = 141,
+    drink_prayer_1 = 143
+);
+potion!(
+    "Restore potion",
+    [
+        Restore(Stat::Attack, 10, 0.30),
+        Restore(Stat::Strength, 10, 0.30),
+        Restore(Stat::Defence, 10, 0.30),
+        Restore(Stat::Ranged, 10, 0.30),
+        Restore(Stat::Magic, 10, 0.30),
+    ],
+    drink_restore_4 = 2430,
+    drink_restore_3 = 127,
+    drink_restore_2 = 129,
+    drink_restore_1 = 131
+);
+potion!(
+    "Super restore",
+    [
+        Restore(Stat::Attack, 8, 0.25),
+        Restore(Stat::Defence, 8, 0.25),
+        Restore(Stat::Strength, 8, 0.25),
+        Restore(Stat::Ranged, 8, 0.25),
+        Restore(Stat::Magic, 8, 0.25),
+        Restore(Stat::Cooking, 8, 0.25),
+        Restore(Stat::Woodcutting, 8, 0.25),
+        Restore(Stat::Fletching, 8, 0.25),
+        Restore(Stat::Fishing, 8, 0.25),
+        Restore(Stat::Firemaking, 8, 0.25),
+        Restore(Stat::Crafting, 8, 0.25),
+        Restore(Stat::Smithing, 8, 0.25),
+        Restore(Stat::Mining, 8, 0.25),
+        Restore(Stat::Herblore, 8, 0.25),
+        Restore(Stat::Agility, 8, 0.25),
+        Restore(Stat::Thieving, 8, 0.25),
+        Restore(Stat::Slayer, 8, 0.25),
+        Restore(Stat::Farming, 8, 0.25),
+        Restore(Stat::Runecraft, 8, 0.25),
+        Restore(Stat::Hunter, 8, 0.25),
+        Restore(Stat::Construction, 8, 0.25),
+        Restore(Stat::Summoning, 8, 0.25),
+        Prayer(8, 0.25),
+    ],
+    drink_super_restore_4 = 3024,
+    drink_super_restore_3 = 3026,
+    drink_super_restore_2 = 3028,
+    drink_super_restore_1 = 3030
+);
+potion!(
+    "Energy potion",
+    [Energy(1000)],
+    drink_energy_4 = 3008,
+    drink_energy_3 = 3010,
+    drink_energy_2 = 3012,
+    drink_energy_1 = 3014
+);
+potion!(
+    "Super energy",
+    [Energy(2000)],
+    drink_super_energy_4 = 3016,
+    drink_super_energy_3 = 3018,
+    drink_super_energy_2 = 3020,
+    drink_super_energy_1 = 3022
+);
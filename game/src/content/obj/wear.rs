@@ -106,6 +106,7 @@ async fn wear_slot(player: &mut Player, inv_slot: usize, obj: Obj) {
     if weapon_changed {
         player.combat_mut().set_combat_style(0);
         player.varp_mut().send_varp(43, 0).await;
+        player.combat_mut().set_autocast(None).await;
     }
     if player.interface().get_slot(InterfaceSlot::Modal) == Some(equipment::STATS) {
         send_equip_bonuses(player).await;
@@ -125,6 +126,7 @@ async fn unwear_slot(player: &mut Player, slot: WearPos) {
     if slot == WearPos::Weapon {
         player.combat_mut().set_combat_style(0);
         player.varp_mut().send_varp(43, 0).await;
+        player.combat_mut().set_autocast(None).await;
     }
     if player.interface().get_slot(InterfaceSlot::Modal) == Some(equipment::STATS) {
         send_equip_bonuses(player).await;
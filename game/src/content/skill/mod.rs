@@ -1 +1,2 @@
 mod mining;
+mod prayer;
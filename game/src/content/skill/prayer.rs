@@ -0,0 +1,60 @@
+const BURY_SEQ: u16 = 827;
+const BURY_DELAY: u16 = 2;
+const PRAY_SEQ: u16 = 645;
+const BURY_RESTORE_DIVISOR: f64 = 10.0;
+
+macro_rules! bones {
+    ($fn_name:ident, $obj_id:expr, $xp:expr) => {
+        #[macros::on_obj(id = $obj_id, op = Op1)]
+        async fn $fn_name() {
+            lock!();
+            clear_slot!();
+            seq!(BURY_SEQ);
+            send_message!("You dig a hole in the ground...");
+            delay!(BURY_DELAY);
+            send_message!("You bury the bones.");
+            give_xp!(stat = Prayer, amount = $xp);
+            player
+                .prayer_mut()
+                .restore(($xp / BURY_RESTORE_DIVISOR).ceil() as u8);
+            unlock!();
+        }
+    };
+}
+
+macro_rules! altar {
+    ($fn_name:ident, $loc_id:expr) => {
+        #[macros::on_loc(id = $loc_id, op = Op1)]
+        async fn $fn_name() {
+            if !player.prayer_mut().recharge() {
+                send_message!("You already have full Prayer points.");
+                return;
+            }
+            seq!(PRAY_SEQ);
+            send_message!("You recharge your Prayer points.");
+        }
+    };
+}
+
+bones!(bury_bones, 526, 4.5);
+bones!(bury_wolf_bones, 2859, 4.5);
+bones!(bury_burnt_bones, 528, 4.5);
+bones!(bury_monkey_bones, 3183, 5.0);
+bones!(bury_bat_bones, 530, 5.3);
+bones!(bury_big_bones, 532, 15.0);
+bones!(bury_jogre_bones, 3125, 15.0);
+bones!(bury_zogre_bones, 4812, 22.5);
+bones!(bury_shaikahan_bones, 3123, 25.0);
+bones!(bury_babydragon_bones, 534, 30.0);
+bones!(bury_wyvern_bones, 6812, 72.0);
+bones!(bury_dragon_bones, 536, 72.0);
+bones!(bury_fayrg_bones, 4830, 84.0);
+bones!(bury_raurg_bones, 4832, 96.0);
+bones!(bury_dagannoth_bones, 6729, 125.0);
+bones!(bury_ourg_bones, 4834, 140.0);
+bones!(bury_frost_dragon_bones, 18830, 180.0);
+
+altar!(pray_at_altar, 409);
+altar!(pray_at_chaos_altar, 411);
+altar!(pray_at_monastery_altar, 2640);
+altar!(pray_at_lumbridge_altar, 36972);
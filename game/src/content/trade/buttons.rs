@@ -0,0 +1,168 @@
+use net::Op;
+
+use crate::player::{Clientbound, Player, STACK_MAX, TradePendingX, TradeStage, trading as ui};
+
+#[macros::on_interface(interface = 336, component = 0)]
+async fn trade_offer() {
+    let slot = slot1 as usize;
+    match op {
+        Op::Op1 => offer(&mut player, slot, 1).await,
+        Op::Op2 => offer(&mut player, slot, 5).await,
+        Op::Op3 => offer(&mut player, slot, 10).await,
+        Op::Op4 => offer(&mut player, slot, STACK_MAX).await,
+        Op::Op5 => prompt_x(&mut player, slot, true).await,
+        Op::Op6 => {
+            let Some(obj) = player.inv().slot(slot) else { return };
+            send_value(&mut player, obj.id).await;
+        }
+        _ => {}
+    }
+}
+
+#[macros::on_interface(interface = 335, component = 30)]
+async fn trade_remove() {
+    let slot = slot1 as usize;
+    match op {
+        Op::Op1 => remove(&mut player, slot, 1).await,
+        Op::Op2 => remove(&mut player, slot, 5).await,
+        Op::Op3 => remove(&mut player, slot, 10).await,
+        Op::Op4 => remove(&mut player, slot, STACK_MAX).await,
+        Op::Op5 => prompt_x(&mut player, slot, false).await,
+        Op::Op6 => {
+            let Some(obj) = player.trade().slot(slot) else { return };
+            send_value(&mut player, obj.id).await;
+        }
+        _ => {}
+    }
+}
+
+#[macros::on_interface(interface = 335, component = 32, op = Op1)]
+async fn trade_partner_value() {
+    let Some(index) = super::partner(&mut player).await else { return };
+    let world = player.world();
+    let Some(obj) = world.player(index).trade().slot(slot1 as usize) else { return };
+    send_value(&mut player, obj.id).await;
+}
+
+#[macros::on_interface(interface = 335, component = 16, op = Op1)]
+async fn trade_accept() {
+    accept(&mut player, TradeStage::Offer).await;
+}
+
+#[macros::on_interface(interface = 335, component = 18, op = Op1)]
+async fn trade_decline() {
+    super::decline_trade(&mut player).await;
+}
+
+#[macros::on_interface(interface = 334, component = 21, op = Op1)]
+async fn trade_confirm() {
+    accept(&mut player, TradeStage::Confirm).await;
+}
+
+#[macros::on_interface(interface = 334, component = 22, op = Op1)]
+async fn trade_confirm_decline() {
+    super::decline_trade(&mut player).await;
+}
+
+async fn offer(player: &mut Player, slot: usize, amount: u32) {
+    if amount == 0 || player.trade().stage() != TradeStage::Offer {
+        return;
+    }
+    let Some(index) = super::partner(player).await else { return };
+    let Some(obj) = player.inv().slot(slot) else { return };
+
+    let stackable = crate::provider::get_obj_type(obj.id as u32).is_some_and(|t| t.stackable);
+    let taken = match stackable {
+        true => amount.min(obj.amount),
+        false => amount.min(player.inv().count(obj.id)),
+    };
+
+    match stackable {
+        true => player.inv_mut().remove_obj(slot, taken).await,
+        false => {
+            player.inv_mut().remove(obj.id, taken).await;
+        }
+    }
+    player.trade_mut().add(obj.id, taken);
+
+    let world = player.world();
+    let mut partner = world.player_mut(index);
+    super::refresh(player, &mut partner).await;
+    super::refresh(&mut partner, player).await;
+}
+
+async fn remove(player: &mut Player, slot: usize, amount: u32) {
+    if amount == 0 || player.trade().stage() != TradeStage::Offer {
+        return;
+    }
+    let Some(index) = super::partner(player).await else { return };
+    let Some(obj) = player.trade_mut().remove(slot, amount) else { return };
+    player.inv_mut().add(obj.id, obj.amount).await;
+
+    let world = player.world();
+    let mut partner = world.player_mut(index);
+    super::refresh(player, &mut partner).await;
+    super::refresh(&mut partner, player).await;
+    partner
+        .if_set_text(
+            ui::OFFER,
+            super::STATUS_TEXT,
+            "<col=FF0000>Other player has removed an item!",
+        )
+        .await;
+}
+
+async fn accept(player: &mut Player, stage: TradeStage) {
+    if player.trade().stage() != stage || player.trade().accepted() {
+        return;
+    }
+    let Some(index) = super::partner(player).await else { return };
+    let world = player.world();
+    let mut partner = world.player_mut(index);
+
+    if !super::check_space(player, &mut partner).await {
+        return;
+    }
+
+    player.trade_mut().set_accepted(true);
+    if !partner.trade().accepted() {
+        super::set_status(player, "Waiting for other player...").await;
+        super::set_status(&mut partner, "Other player has accepted.").await;
+        return;
+    }
+
+    match stage {
+        TradeStage::Offer => {
+            super::open_confirm(player, &partner).await;
+            super::open_confirm(&mut partner, player).await;
+        }
+        TradeStage::Confirm => super::complete(player, &mut partner).await,
+    }
+}
+
+async fn send_value(player: &mut Player, obj_id: u16) {
+    let name = crate::provider::get_obj_type(obj_id as u32)
+        .map(|t| t.name.as_str())
+        .unwrap_or("null");
+    let value = crate::provider::get_obj_value(obj_id as u32);
+    player
+        .send_message(format!("{}: currently costs {} coins.", name, value))
+        .await;
+}
+
+async fn prompt_x(player: &mut Player, slot: usize, offer: bool) {
+    player.trade_mut().set_pending_x(TradePendingX { slot, offer });
+    player
+        .count_prompt_mut()
+        .prompt("Enter Amount:", |p: &mut Player, v| Box::pin(resume_count(p, v)))
+        .await;
+}
+
+async fn resume_count(player: &mut Player, value: u32) {
+    let Some(pending) = player.trade_mut().take_pending_x() else { return };
+    if pending.offer {
+        offer(player, pending.slot, value).await;
+    } else {
+        remove(player, pending.slot, value).await;
+    }
+}
@@ -0,0 +1,266 @@
+mod buttons;
+mod request;
+
+use net::{InvType, if_events, if_set_events};
+
+use crate::player::{Clientbound, InterfaceSlot, Player, TRADE_OFFER_INV, TradeStage, trade_fits, trading as ui};
+
+const OFFER_COMPONENT: u16 = 30;
+const PARTNER_OFFER_COMPONENT: u16 = 32;
+const INV_COMPONENT: u16 = 0;
+
+const TITLE_TEXT: u16 = 15;
+const STATUS_TEXT: u16 = 36;
+const FREE_SLOTS_TEXT: u16 = 21;
+const OFFER_VALUE_TEXT: u16 = 43;
+const PARTNER_VALUE_TEXT: u16 = 44;
+
+const CONFIRM_TITLE_TEXT: u16 = 45;
+const CONFIRM_STATUS_TEXT: u16 = 33;
+const CONFIRM_OFFER_TEXT: u16 = 37;
+const CONFIRM_PARTNER_TEXT: u16 = 41;
+
+pub(super) async fn open(player: &mut Player, partner: &mut Player) {
+    player.trade_mut().begin(partner.index);
+    player.interface_mut().open_slot(InterfaceSlot::Modal, ui::OFFER).await;
+    player
+        .interface_mut()
+        .open_slot(InterfaceSlot::Inventory, ui::INV)
+        .await;
+
+    player
+        .set_items_options(
+            ui::INV,
+            INV_COMPONENT,
+            InvType::Inv.key(),
+            4,
+            7,
+            &[
+                "Offer",
+                "Offer-5",
+                "Offer-10",
+                "Offer-All",
+                "Offer-X",
+                "Value<col=FF9040>",
+            ],
+        )
+        .await;
+    player
+        .if_set_events(if_set_events!(
+            interface_id: ui::INV,
+            component_id: INV_COMPONENT,
+            slots: [0 => 27],
+            right_click[0, 1, 2, 3, 4, 5, 9],
+            depth[1]
+        ))
+        .await;
+
+    player
+        .set_items_options(
+            ui::OFFER,
+            OFFER_COMPONENT,
+            TRADE_OFFER_INV,
+            4,
+            7,
+            &["Remove", "Remove-5", "Remove-10", "Remove-All", "Remove-X", "Value"],
+        )
+        .await;
+    player
+        .if_set_events(if_set_events!(
+            interface_id: ui::OFFER,
+            component_id: OFFER_COMPONENT,
+            slots: [0 => 27],
+            right_click[0, 1, 2, 3, 4, 5, 9],
+            depth[1]
+        ))
+        .await;
+
+    player
+        .set_items_options(ui::OFFER, PARTNER_OFFER_COMPONENT, TRADE_OFFER_INV, 4, 7, &["Value"])
+        .await;
+    player
+        .if_set_events(if_set_events!(
+            interface_id: ui::OFFER,
+            component_id: PARTNER_OFFER_COMPONENT,
+            slots: [0 => 27],
+            right_click[0, 9]
+        ))
+        .await;
+
+    player
+        .if_set_text(ui::OFFER, TITLE_TEXT, format!("Trading With: {}", partner.username))
+        .await;
+    player.inv_mut().flush().await;
+    refresh(player, partner).await;
+}
+
+pub(super) async fn refresh(player: &mut Player, partner: &mut Player) {
+    player.trade_mut().set_accepted(false);
+    partner.trade_mut().set_accepted(false);
+
+    player.trade_mut().flush().await;
+    let entries = partner.trade().entries();
+    player.update_inv(InvType::Custom(TRADE_OFFER_INV), true, entries).await;
+
+    let value = player.trade().value();
+    player
+        .if_set_text(ui::OFFER, OFFER_VALUE_TEXT, format!("Value: {} coins", value))
+        .await;
+    let value = partner.trade().value();
+    player
+        .if_set_text(ui::OFFER, PARTNER_VALUE_TEXT, format!("Value: {} coins", value))
+        .await;
+
+    let free = partner.inv().free_slots();
+    player
+        .if_set_text(
+            ui::OFFER,
+            FREE_SLOTS_TEXT,
+            format!("{} has {} free inventory slots.", partner.username, free),
+        )
+        .await;
+    player.if_set_text(ui::OFFER, STATUS_TEXT, "").await;
+}
+
+pub(super) async fn set_status(player: &mut Player, text: &str) {
+    let (interface, component) = match player.trade().stage() {
+        TradeStage::Offer => (ui::OFFER, STATUS_TEXT),
+        TradeStage::Confirm => (ui::CONFIRM, CONFIRM_STATUS_TEXT),
+    };
+    player.if_set_text(interface, component, text).await;
+}
+
+pub(super) async fn open_confirm(player: &mut Player, partner: &Player) {
+    player.trade_mut().set_stage(TradeStage::Confirm);
+    player
+        .interface_mut()
+        .open_slot(InterfaceSlot::Modal, ui::CONFIRM)
+        .await;
+    player.interface_mut().close_slot(InterfaceSlot::Inventory).await;
+
+    player
+        .if_set_text(
+            ui::CONFIRM,
+            CONFIRM_TITLE_TEXT,
+            format!("Trading With: {}", partner.username),
+        )
+        .await;
+    let text = describe(player);
+    player.if_set_text(ui::CONFIRM, CONFIRM_OFFER_TEXT, text).await;
+    let text = describe(partner);
+    player.if_set_text(ui::CONFIRM, CONFIRM_PARTNER_TEXT, text).await;
+}
+
+fn describe(player: &Player) -> String {
+    let lines: Vec<String> = player
+        .trade()
+        .offered()
+        .map(|obj| {
+            let name = crate::provider::get_obj_type(obj.id as u32)
+                .map(|t| t.name.as_str())
+                .unwrap_or("null");
+            match obj.amount {
+                1 => name.to_string(),
+                n => format!("{} x {}", name, n),
+            }
+        })
+        .collect();
+
+    match lines.is_empty() {
+        true => "Absolutely nothing!".to_string(),
+        false => lines.join("<br>"),
+    }
+}
+
+pub(super) async fn check_space(player: &mut Player, partner: &mut Player) -> bool {
+    if !trade_fits(partner.inv().slots(), player.trade().offered()) {
+        player
+            .send_message("Other player doesn't have enough inventory space for this trade.")
+            .await;
+        partner
+            .send_message("You don't have enough inventory space for this trade.")
+            .await;
+        return false;
+    }
+    if !trade_fits(player.inv().slots(), partner.trade().offered()) {
+        player
+            .send_message("You don't have enough inventory space for this trade.")
+            .await;
+        partner
+            .send_message("Other player doesn't have enough inventory space for this trade.")
+            .await;
+        return false;
+    }
+    true
+}
+
+pub(super) async fn complete(player: &mut Player, partner: &mut Player) {
+    if !check_space(player, partner).await {
+        decline_with(player, partner).await;
+        return;
+    }
+
+    let given = player.trade_mut().end();
+    let received = partner.trade_mut().end();
+    for obj in &received {
+        player.inv_mut().insert(obj.id, obj.amount);
+    }
+    for obj in &given {
+        partner.inv_mut().insert(obj.id, obj.amount);
+    }
+    player.inv_mut().flush().await;
+    partner.inv_mut().flush().await;
+
+    close_trade_interfaces(player).await;
+    close_trade_interfaces(partner).await;
+    player.send_message("Accepted trade.").await;
+    partner.send_message("Accepted trade.").await;
+
+    tracing::info!(
+        player = player.username,
+        partner = partner.username,
+        given = ?given,
+        received = ?received,
+        "Trade completed"
+    );
+}
+
+pub async fn close_trade_interfaces(player: &mut Player) {
+    player.interface_mut().close_slot(InterfaceSlot::Modal).await;
+    player.interface_mut().close_slot(InterfaceSlot::Inventory).await;
+}
+
+async fn end_session(player: &mut Player) {
+    for obj in player.trade_mut().end() {
+        player.inv_mut().insert(obj.id, obj.amount);
+    }
+    player.inv_mut().flush().await;
+    close_trade_interfaces(player).await;
+}
+
+async fn decline_with(player: &mut Player, partner: &mut Player) {
+    end_session(player).await;
+    end_session(partner).await;
+    partner.send_message("Other player declined trade.").await;
+}
+
+pub(super) async fn partner(player: &mut Player) -> Option<usize> {
+    let index = player.trade().partner()?;
+    let world = player.world();
+    let trading = world.players.contains(index) && world.player(index).trade().partner() == Some(player.index);
+    if !trading {
+        end_session(player).await;
+        return None;
+    }
+    Some(index)
+}
+
+pub async fn decline_trade(player: &mut Player) {
+    let Some(index) = partner(player).await else {
+        return;
+    };
+
+    let world = player.world();
+    let mut partner = world.player_mut(index);
+    decline_with(player, &mut partner).await;
+}
@@ -0,0 +1,30 @@
+const REQUEST_MSG_TYPE: u16 = 100;
+
+#[macros::on_player(op = Op4)]
+async fn trade_with() {
+    let world = player.world();
+    if !world.players.contains(target_index) {
+        return;
+    }
+    let mut target = world.player_mut(target_index);
+
+    if target.trade().partner().is_some() || target.logging_out {
+        drop(target);
+        player.send_message("Other player is busy at the moment.").await;
+        return;
+    }
+
+    if target.trade().requested() == Some(player.index) {
+        super::open(&mut player, &mut target).await;
+        super::open(&mut target, &mut player).await;
+        return;
+    }
+
+    player.trade_mut().set_requested(Some(target_index));
+    let name = player.username.clone();
+    target
+        .send_message_from(REQUEST_MSG_TYPE, name, "wishes to trade with you.")
+        .await;
+    drop(target);
+    player.send_message("Sending trade offer...").await;
+}
@@ -2,6 +2,7 @@ mod combat;
 mod inventory;
 mod logout;
 mod orbs;
+mod prayer;
 mod settings;
 mod skills;
 mod worldmap;
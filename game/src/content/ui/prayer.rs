@@ -0,0 +1,12 @@
+use crate::player::Prayer;
+
+#[macros::on_interface(op = 1, interface = 271, component = 8)]
+async fn toggle_prayer() {
+    let Ok(prayer) = Prayer::try_from(slot1 as u8) else { return };
+    player.prayer_mut().toggle(prayer).await;
+}
+
+#[macros::on_interface(op = 1, interface = 749, component = 1)]
+async fn quick_prayers() {
+    player.prayer_mut().toggle_quick().await;
+}
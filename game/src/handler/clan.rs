@@ -0,0 +1,23 @@
+use macros::message_handler;
+use net::{ClanJoinChat, ClanKickUser, ClanSetRank};
+
+use super::MessageHandler;
+use crate::{content, player::Player};
+
+#[message_handler]
+async fn handle_clan_join(player: &mut Player, msg: ClanJoinChat) {
+    match msg.name.is_empty() {
+        true => content::leave_clan(player).await,
+        false => content::join_clan(player, &msg.name).await,
+    }
+}
+
+#[message_handler]
+async fn handle_clan_kick(player: &mut Player, msg: ClanKickUser) {
+    content::kick_clan_member(player, &msg.name).await;
+}
+
+#[message_handler]
+async fn handle_clan_set_rank(player: &mut Player, msg: ClanSetRank) {
+    content::set_clan_rank(player, &msg.name, msg.rank).await;
+}
@@ -1,14 +1,14 @@
 use macros::message_handler;
-use net::{ExamLoc, IfButton, Op, OpLoc, OpNpc, OpNpcT, OpPlayer};
+use net::{ExamLoc, IfButton, IfButtonT, Op, OpLoc, OpNpc, OpNpcT, OpPlayer, OpPlayerT, SelectedComponent};
 
 use super::{
     MessageHandler,
     dispatch::{CONTENT_HANDLERS, ContentTarget, run_action},
 };
 use crate::{
-    content::CombatTarget,
+    content::{CombatSpell, CombatTarget},
     entity::WalkTarget,
-    player::{Clientbound, InteractionTarget, Player, is_action_locked},
+    player::{Clientbound, InteractionTarget, Player, is_action_locked, tabs},
     world::Position,
 };
 
@@ -83,8 +83,9 @@ async fn handle_opnpct(player: &mut Player, msg: OpNpcT) {
         return;
     }
 
+    let spell = msg.selected.and_then(selected_spell);
     let target = CombatTarget::Npc(msg.npc_index as usize);
-    if player.combat().combat_target() == Some(target) {
+    if spell.is_none() && player.combat().combat_target() == Some(target) {
         return;
     }
 
@@ -99,9 +100,43 @@ async fn handle_opnpct(player: &mut Player, msg: OpNpcT) {
         player.movement_mut().set_run(true).await;
     }
 
+    if let Some(spell) = spell {
+        player.combat_mut().queue_spell(spell);
+    }
     run_action(player, crate::content::start_combat(target));
 }
 
+#[message_handler]
+async fn handle_opplayert(player: &mut Player, msg: OpPlayerT) {
+    if is_action_locked(player) {
+        return;
+    }
+
+    let Some(spell) = selected_spell(msg.selected) else { return };
+    let index = msg.player_index as usize;
+
+    player.cancel_action(true).await;
+    player.entity.stop();
+
+    if !player.world().players.contains(index) || index == player.index {
+        return;
+    }
+
+    if msg.ctrl_run && !player.movement().running {
+        player.movement_mut().set_run(true).await;
+    }
+
+    player.combat_mut().queue_spell(spell);
+    run_action(player, crate::content::start_combat(CombatTarget::Player(index)));
+}
+
+fn selected_spell(selected: SelectedComponent) -> Option<&'static CombatSpell> {
+    if selected.interface != tabs::MAGIC {
+        return None;
+    }
+    crate::content::combat_spell(selected.component)
+}
+
 #[message_handler]
 async fn handle_opplayer(player: &mut Player, msg: OpPlayer) {
     if is_action_locked(player) {
@@ -130,6 +165,15 @@ async fn handle_opplayer(player: &mut Player, msg: OpPlayer) {
         return;
     }
 
+    if msg.op == Op::Op2 {
+        drop(world);
+        if msg.ctrl_run && !player.movement().running {
+            player.movement_mut().set_run(true).await;
+        }
+        run_action(player, crate::content::follow(index));
+        return;
+    }
+
     let target_pos = world.player(index).position;
     player
         .interaction_mut()
@@ -191,6 +235,26 @@ async fn handle_ifbutton(player: &mut Player, msg: IfButton) {
     run_action(player, handler(target));
 }
 
+#[message_handler]
+async fn handle_ifbuttont(player: &mut Player, msg: IfButtonT) {
+    if is_action_locked(player) {
+        return;
+    }
+
+    if msg.selected.interface != tabs::MAGIC || msg.interface != tabs::INVENTORY {
+        tracing::debug!(?msg, "Unhandled IfButtonT");
+        return;
+    }
+
+    let Some(spell) = crate::content::alchemy_spell(msg.selected.component) else { return };
+    if player.inv().slot(msg.slot as usize).is_none_or(|obj| obj.id != msg.obj) {
+        return;
+    }
+
+    player.cancel_action(false).await;
+    run_action(player, crate::content::alchemy(spell, msg.slot));
+}
+
 pub fn try_dispatch_obj(player: &mut Player, op: Op, slot: u16) -> bool {
     let Some(obj) = player.inv().slot(slot as usize) else {
         return false;
@@ -4,13 +4,23 @@ use util::format_sentence;
 
 use super::MessageHandler;
 use crate::{
-    player::{ChatMask, Player},
+    content,
+    player::{ChatMask, Clientbound, Player},
     provider,
 };
 
 #[message_handler]
 async fn handle(player: &mut Player, msg: MessagePublic) {
+    if player.mute().is_muted() {
+        player.send_message("You are muted and cannot talk.").await;
+        return;
+    }
+
     let message = provider::decode_huffman(&msg.payload, msg.text_len);
+    if let Some(text) = message.strip_prefix('/') {
+        content::send_clan_message(player, &format_sentence(text)).await;
+        return;
+    }
 
     player.player_info.add_mask(ChatMask {
         message: format_sentence(&message),
@@ -1,3 +1,4 @@
+mod clan;
 mod client_cheat;
 mod dialogue;
 mod dispatch;
@@ -8,6 +9,8 @@ mod message_public;
 mod moveclick;
 mod objstack;
 mod resumecount;
+mod resumename;
+mod social;
 
 use std::{any::TypeId, collections::HashMap, future::Future, pin::Pin};
 
@@ -0,0 +1,11 @@
+use macros::message_handler;
+use net::ResumeNameDialog;
+
+use super::MessageHandler;
+use crate::player::Player;
+
+#[message_handler]
+async fn handle_resume_name(player: &mut Player, msg: ResumeNameDialog) {
+    let Some(handler) = player.name_prompt_mut().take() else { return };
+    handler(player, msg.name).await;
+}
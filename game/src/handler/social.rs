@@ -0,0 +1,98 @@
+use macros::message_handler;
+use net::{FriendlistAdd, FriendlistDel, IgnorelistAdd, IgnorelistDel, MessagePrivate, SetChatFilterSettings};
+use util::{format_sentence, normalize_name};
+
+use super::MessageHandler;
+use crate::{
+    content,
+    player::{Clientbound, Player, PrivateChat, broadcast_status, next_message_id},
+    provider,
+};
+
+#[message_handler]
+async fn handle_friendlist_add(player: &mut Player, msg: FriendlistAdd) {
+    if player.social_mut().add_friend(&msg.name).await {
+        let world = player.world();
+        broadcast_status(&world, player, true).await;
+        content::sync_clan_friend(player, &msg.name, true).await;
+    }
+}
+
+#[message_handler]
+async fn handle_friendlist_del(player: &mut Player, msg: FriendlistDel) {
+    if player.social_mut().remove_friend(&msg.name) {
+        let world = player.world();
+        broadcast_status(&world, player, true).await;
+        content::sync_clan_friend(player, &msg.name, false).await;
+    }
+}
+
+#[message_handler]
+async fn handle_ignorelist_add(player: &mut Player, msg: IgnorelistAdd) {
+    if player.social_mut().add_ignore(&msg.name).await {
+        let world = player.world();
+        broadcast_status(&world, player, true).await;
+    }
+}
+
+#[message_handler]
+async fn handle_ignorelist_del(player: &mut Player, msg: IgnorelistDel) {
+    if player.social_mut().remove_ignore(&msg.name).await {
+        let world = player.world();
+        broadcast_status(&world, player, true).await;
+    }
+}
+
+#[message_handler]
+async fn handle_chat_filter(player: &mut Player, msg: SetChatFilterSettings) {
+    let Ok(mode) = PrivateChat::try_from(msg.private) else { return };
+    if mode == player.social().private_chat() {
+        return;
+    }
+    player.social_mut().set_private_chat(mode).await;
+    let world = player.world();
+    broadcast_status(&world, player, true).await;
+}
+
+#[message_handler]
+async fn handle_message_private(player: &mut Player, msg: MessagePrivate) {
+    if player.mute().is_muted() {
+        player.send_message("You are muted and cannot talk.").await;
+        return;
+    }
+
+    let world = player.world();
+    let Some(index) = world.find_player(&msg.to).filter(|&i| i != player.index) else {
+        player.send_message("That player is currently offline.").await;
+        return;
+    };
+
+    if player.social().private_chat() == PrivateChat::Off {
+        player.social_mut().set_private_chat(PrivateChat::Friends).await;
+        broadcast_status(&world, player, true).await;
+    }
+
+    let mut target = world.player_mut(index);
+    if !target.social().is_visible_to(&normalize_name(&player.username)) {
+        drop(target);
+        player.send_message("That player is currently offline.").await;
+        return;
+    }
+
+    let text = format_sentence(&provider::decode_huffman(&msg.payload, msg.text_len));
+    let payload = provider::encode_huffman(&text);
+    let message_id = next_message_id();
+
+    target
+        .message_private_from(
+            player.username.clone(),
+            message_id,
+            player.rights.into(),
+            text.len(),
+            payload.clone(),
+        )
+        .await;
+    let to = target.username.clone();
+    drop(target);
+    player.message_private_echo(to, text.len(), payload).await;
+}
@@ -10,7 +10,10 @@ use tracing_subscriber::EnvFilter;
 use crate::{
     config::AppConfig,
     provider::ProviderContext,
-    service::{GameLoginService, ServiceManager, WorldLoginService, WorldLoginServiceParameters, WorldService},
+    service::{
+        GameLoginService, ModerationService, ServiceManager, WorldLoginService, WorldLoginServiceParameters,
+        WorldModerationService, WorldPopulationService, WorldService,
+    },
     world::World,
 };
 
@@ -28,13 +31,16 @@ mod world;
 
 module! {
     GameModule {
-        components = [WorldLoginService],
+        components = [WorldLoginService, WorldModerationService],
         providers = [],
 
         use dyn PersistenceModuleInterface {
             components = [
                 dyn persistence::AccountRepository,
                 dyn persistence::PlayerRepository,
+                dyn persistence::SocialRepository,
+                dyn persistence::ClanRepository,
+                dyn persistence::PunishmentRepository,
             ],
             providers = []
         }
@@ -72,18 +78,41 @@ async fn main() -> anyhow::Result<()> {
     .await?;
 
     let world = Arc::new(World::default());
+    world.set_safe_zones(app_config.game.safe_zones.clone());
+    world.set_world_id(app_config.game.world_id);
+    let clans: Arc<dyn persistence::ClanRepository> = persistence.resolve();
+    world.clans.load(clans.find_all().await?);
     world.init();
 
+    let client_version = app_config.game.client_version;
+    let checksums = filesystem::index_checksums(&cache).iter().map(|c| c.crc).collect();
+
+    let world_population = Arc::new(WorldPopulationService::new(
+        app_config.game.world_id,
+        world.clone(),
+        persistence.resolve(),
+    ));
+
     let game = GameModule::builder(persistence)
         .with_component_parameters::<WorldLoginService>(WorldLoginServiceParameters {
             config: app_config.game,
             world: world.clone(),
+            checksums,
+            queue: Default::default(),
         })
         .build();
 
     let login_service: Arc<dyn GameLoginService> = game.resolve();
+    let moderation: Arc<dyn ModerationService> = game.resolve();
+    world.set_moderation(moderation);
     let world_service = WorldService::new(world.clone());
-    let tcp_service = TcpService::new(app_config.tcp, cache.clone(), login_service)?;
+    let tcp_service = TcpService::new(
+        app_config.tcp,
+        cache.clone(),
+        login_service,
+        world_population.clone(),
+        client_version,
+    )?;
 
     service_manager.spawn("TCP Service", |cancel, tx| async move {
         tcp_service.run_until(cancel.cancelled(), Some(tx)).await
@@ -98,6 +127,12 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    service_manager.spawn("Population Service", move |cancel, tx| async move {
+        let _ = tx.send(());
+        world_population.run_until(cancel).await;
+        Ok(())
+    });
+
     service_manager
         .monitor()
         .on_ready(|| {
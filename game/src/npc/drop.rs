@@ -0,0 +1,96 @@
+use rand::Rng;
+
+use crate::provider;
+
+const MAX_TABLE_DEPTH: u8 = 4;
+
+pub enum DropItem {
+    Obj {
+        obj_id: u16,
+        min: u32,
+        max: u32,
+        noted: bool,
+    },
+    Table(String),
+}
+
+pub struct DropEntry {
+    pub item: DropItem,
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl DropEntry {
+    fn chance(&self) -> f64 {
+        self.numerator as f64 / self.denominator.max(1) as f64
+    }
+}
+
+#[derive(Default)]
+pub struct DropTable {
+    pub always: Vec<DropEntry>,
+    pub main: Vec<DropEntry>,
+    pub tertiary: Vec<DropEntry>,
+}
+
+pub fn roll_drops(npc_id: u16) -> Vec<(u16, u32)> {
+    let Some(table) = provider::get_npc_drops(npc_id as u32) else {
+        return Vec::new();
+    };
+
+    let mut rng = rand::rng();
+    let mut drops = Vec::new();
+
+    for entry in &table.always {
+        resolve(&entry.item, &mut rng, &mut drops, 0);
+    }
+
+    if let Some(entry) = pick_weighted(&table.main, &mut rng) {
+        resolve(&entry.item, &mut rng, &mut drops, 0);
+    }
+
+    for entry in &table.tertiary {
+        if rng.random_bool(entry.chance().min(1.0)) {
+            resolve(&entry.item, &mut rng, &mut drops, 0);
+        }
+    }
+
+    drops
+}
+
+fn pick_weighted<'a>(entries: &'a [DropEntry], rng: &mut impl Rng) -> Option<&'a DropEntry> {
+    let mut roll: f64 = rng.random();
+    entries.iter().find(|entry| {
+        roll -= entry.chance();
+        roll < 0.0
+    })
+}
+
+fn resolve(item: &DropItem, rng: &mut impl Rng, drops: &mut Vec<(u16, u32)>, depth: u8) {
+    match item {
+        DropItem::Obj {
+            obj_id,
+            min,
+            max,
+            noted,
+        } => {
+            let amount = rng.random_range(*min..=(*max).max(*min));
+            let obj_id = if *noted { noted_id(*obj_id) } else { *obj_id };
+            drops.push((obj_id, amount));
+        }
+        DropItem::Table(name) if depth < MAX_TABLE_DEPTH => {
+            let entries = provider::get_shared_drops(name);
+            if let Some(entry) = pick_weighted(entries, rng) {
+                resolve(&entry.item, rng, drops, depth + 1);
+            }
+        }
+        DropItem::Table(name) => tracing::warn!(table = name, "Shared drop table nested too deep"),
+    }
+}
+
+fn noted_id(obj_id: u16) -> u16 {
+    provider::get_obj_type(obj_id as u32)
+        .filter(|t| t.noted_template.is_none())
+        .and_then(|t| t.noted_id)
+        .map_or(obj_id, |id| id as u16)
+}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use crate::content::CombatTarget;
+
+const DECAY_TICKS: u16 = 100;
+
+struct Contribution {
+    damage: u32,
+    idle_ticks: u16,
+}
+
+#[derive(Default)]
+pub struct DamageLedger {
+    entries: HashMap<CombatTarget, Contribution>,
+}
+
+impl DamageLedger {
+    pub fn record(&mut self, source: CombatTarget, damage: u16) {
+        let entry = self.entries.entry(source).or_insert(Contribution {
+            damage: 0,
+            idle_ticks: 0,
+        });
+        entry.damage += damage as u32;
+        entry.idle_ticks = 0;
+    }
+
+    pub fn tick(&mut self) {
+        self.entries.retain(|_, c| {
+            c.idle_ticks += 1;
+            c.idle_ticks < DECAY_TICKS
+        });
+    }
+
+    pub fn top_damager(&self) -> Option<CombatTarget> {
+        self.entries
+            .iter()
+            .max_by_key(|&(_, c)| c.damage)
+            .map(|(&source, _)| source)
+    }
+
+    pub fn contributors(&self) -> impl Iterator<Item = (CombatTarget, u32)> + '_ {
+        self.entries.iter().map(|(&source, c)| (source, c.damage))
+    }
+
+    pub fn damage_from(&self, source: CombatTarget) -> u32 {
+        self.entries.get(&source).map_or(0, |c| c.damage)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -1,18 +1,23 @@
 mod action;
+mod drop;
 mod gni;
 mod info;
+mod ledger;
 mod mask;
 
 use std::ops::{Deref, DerefMut};
 
 pub use action::{NpcActionState, NpcRef, active_shared, delay, fire_action, resolve as resolve_action};
+pub use drop::{DropEntry, DropItem, DropTable, roll_drops};
 pub use gni::encode_npc_info;
 pub use info::NpcInfo;
+pub use ledger::DamageLedger;
 pub use mask::{FaceEntityMask, SeqMask, SpotAnim1Mask, SpotAnim2Mask};
 use rand::Rng;
 use strum::IntoEnumIterator;
 
 use crate::{
+    content::CombatTarget,
     entity::{Entity, Hit, MaskBlock, MoveStep, Seq, SeqBuilder, SpotAnim, SpotAnimBuilder},
     provider,
     world::{Direction, Position, Teleport},
@@ -39,6 +44,9 @@ pub struct NpcCombat {
     pub death_seq: u16,
     pub max_hit: u16,
     pub atk_range: u16,
+    pub aggro_radius: u16,
+    pub tolerance_ticks: u16,
+    pub always_aggressive: bool,
 }
 
 impl Default for NpcCombat {
@@ -61,6 +69,9 @@ impl Default for NpcCombat {
             death_seq: 836,
             max_hit: 1,
             atk_range: 0,
+            aggro_radius: 0,
+            tolerance_ticks: 1000,
+            always_aggressive: false,
         }
     }
 }
@@ -91,6 +102,7 @@ pub struct Npc {
     pub max_hp: u32,
     pub combat: NpcCombat,
     pub combat_target: Option<usize>,
+    pub damage_ledger: DamageLedger,
     death_timer: Option<u16>,
 }
 
@@ -118,6 +130,7 @@ impl Npc {
             max_hp,
             combat,
             combat_target: None,
+            damage_ledger: DamageLedger::default(),
             death_timer: None,
         }
     }
@@ -134,11 +147,14 @@ impl Npc {
         matches!(self.death_timer, Some(0))
     }
 
-    pub fn damage(&mut self, mut hit: Hit) -> bool {
+    pub fn damage(&mut self, mut hit: Hit, source: Option<CombatTarget>) -> bool {
         if self.death_timer.is_some() {
             return false;
         }
         hit.damage = hit.damage.min(self.current_hp as u16);
+        if let Some(source) = source {
+            self.damage_ledger.record(source, hit.damage);
+        }
         self.current_hp -= hit.damage as u32;
         hit.hp_ratio = (self.current_hp * 255 / self.max_hp) as u8;
         self.add_hit(hit);
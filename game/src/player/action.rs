@@ -124,6 +124,7 @@ pub fn poll_action(state: &mut ActionState) -> Poll<()> {
 pub fn send_message(player: &mut Player, text: &str) {
     let frame = MessageGame {
         msg_type: 0,
+        name: None,
         text: text.to_string(),
     }
     .encode();
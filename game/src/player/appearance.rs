@@ -32,6 +32,7 @@ impl Appearance {
             colors: self.colors,
             display_name: self.player.username.clone(),
             combat_level: self.player.stat().combat_level(),
+            head_icon: self.player.prayer().head_icon(),
             worn: *worn,
         }
     }
@@ -220,6 +220,7 @@ impl Bank {
             if weapon_removed {
                 self.player.combat_mut().set_combat_style(0);
                 self.player.varp_mut().send_varp(43, 0).await;
+                self.player.combat_mut().set_autocast(None).await;
             }
         }
 
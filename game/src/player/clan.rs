@@ -0,0 +1,46 @@
+use macros::player_system;
+use persistence::PlayerData;
+
+use crate::{
+    player::{
+        PlayerSnapshot,
+        system::{PlayerInitContext, PlayerSystem},
+    },
+    world::World,
+};
+
+/// The clan channel a player is talking in, named by its owner.
+pub struct Clan {
+    channel: Option<String>,
+}
+
+impl Clan {
+    pub fn channel(&self) -> Option<&str> {
+        self.channel.as_deref()
+    }
+
+    pub fn set_channel(&mut self, owner: Option<String>) {
+        self.channel = owner;
+    }
+
+    pub fn take_channel(&mut self) -> Option<String> {
+        self.channel.take()
+    }
+}
+
+#[player_system]
+impl PlayerSystem for Clan {
+    type TickContext = ();
+
+    fn create(ctx: &PlayerInitContext) -> Self {
+        Self {
+            channel: ctx.player_data.clan_channel.clone(),
+        }
+    }
+
+    fn tick_context(_: &std::sync::Arc<World>, _: &PlayerSnapshot) {}
+
+    fn persist(&self, data: &mut PlayerData) {
+        data.clan_channel = self.channel.clone();
+    }
+}
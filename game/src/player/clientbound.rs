@@ -1,8 +1,10 @@
 use net::{
-    IfCloseSub, IfOpenSub, IfOpenTop, IfSetAnim, IfSetEvents, IfSetNpcHead, IfSetPlayerHead, IfSetText, InvEntry,
-    InvType, LocAddChange, LocDel, Logout, MapProjAnim, MessageGame, MidiJingle, MinimapToggle, ObjAdd, ObjCount,
-    ObjDel, OutboxExt, RunClientScript, ScriptArg, SetPlayerOp, UpdateInvFull, UpdateRunEnergy, UpdateStat,
-    VarbitLarge, VarbitSmall, VarcLarge, VarcSmall, VarpLarge, VarpSmall, ZoneFrame,
+    ChatFilterSettingsPrivateChat, ClanMemberEntry, FriendEntry, IfCloseSub, IfOpenSub, IfOpenTop, IfSetAnim,
+    IfSetEvents, IfSetNpcHead, IfSetPlayerHead, IfSetText, InvEntry, InvType, LeaveClanChannel, LocAddChange, LocDel,
+    Logout, MapProjAnim, MessageClanChannel, MessageGame, MessagePrivateEcho, MessagePrivateFrom, MidiJingle,
+    MinimapToggle, ObjAdd, ObjCount, ObjDel, OutboxExt, RunClientScript, ScriptArg, SetPlayerOp, UpdateClanChannel,
+    UpdateFriendList, UpdateIgnoreList, UpdateInvFull, UpdateRunEnergy, UpdateStat, VarbitLarge, VarbitSmall,
+    VarcLarge, VarcSmall, VarpLarge, VarpSmall, ZoneFrame,
 };
 
 use super::Player;
@@ -11,6 +13,7 @@ use super::Player;
 #[allow(async_fn_in_trait)]
 pub trait Clientbound {
     async fn send_message(&mut self, text: impl Into<String> + Send);
+    async fn send_message_from(&mut self, msg_type: u16, name: impl Into<String> + Send, text: impl Into<String> + Send);
     async fn logout(&mut self);
     async fn play_jingle(&mut self, id: u16);
 
@@ -41,6 +44,16 @@ pub trait Clientbound {
 
     async fn set_player_op(&mut self, slot: u8, top: bool, op: impl Into<String> + Send);
 
+    async fn update_friend_list(&mut self, friends: Vec<FriendEntry>);
+    async fn update_ignore_list(&mut self, names: Vec<String>);
+    async fn message_private_from(&mut self, from: String, message_id: u64, rights: u8, text_len: usize, payload: Vec<u8>);
+    async fn message_private_echo(&mut self, to: String, text_len: usize, payload: Vec<u8>);
+    async fn set_private_chat_mode(&mut self, mode: u8);
+
+    async fn update_clan_channel(&mut self, owner: String, name: String, kick_rank: i8, members: Vec<ClanMemberEntry>);
+    async fn leave_clan_channel(&mut self);
+    async fn message_clan_channel(&mut self, from: String, channel: String, message_id: u64, rights: u8, text_len: usize, payload: Vec<u8>);
+
     async fn loc_add_change(&mut self, zone_frame: ZoneFrame, loc_id: u16, loc_type: u8, rotation: u8, packed_offset: u8);
     async fn loc_del(&mut self, zone_frame: ZoneFrame, loc_type: u8, rotation: u8, packed_offset: u8);
     async fn obj_add(&mut self, zone_frame: ZoneFrame, obj_id: u16, amount: u32, packed_offset: u8);
@@ -54,10 +67,15 @@ pub trait Clientbound {
 #[rustfmt::skip]
 impl Clientbound for Player {
     async fn send_message(&mut self, text: impl Into<String> + Send) {
-        self.outbox.write(MessageGame { msg_type: 0, text: text.into() }).await;
+        self.outbox.write(MessageGame { msg_type: 0, name: None, text: text.into() }).await;
+    }
+
+    async fn send_message_from(&mut self, msg_type: u16, name: impl Into<String> + Send, text: impl Into<String> + Send) {
+        self.outbox.write(MessageGame { msg_type, name: Some(name.into()), text: text.into() }).await;
     }
 
     async fn logout(&mut self) {
+        self.logging_out = true;
         self.outbox.write(Logout).await;
     }
 
@@ -162,6 +180,38 @@ impl Clientbound for Player {
         self.outbox.write(SetPlayerOp { slot, top, op: op.into() }).await;
     }
 
+    async fn update_friend_list(&mut self, friends: Vec<FriendEntry>) {
+        self.outbox.write(UpdateFriendList { friends }).await;
+    }
+
+    async fn update_ignore_list(&mut self, names: Vec<String>) {
+        self.outbox.write(UpdateIgnoreList { names }).await;
+    }
+
+    async fn message_private_from(&mut self, from: String, message_id: u64, rights: u8, text_len: usize, payload: Vec<u8>) {
+        self.outbox.write(MessagePrivateFrom { from, message_id, rights, text_len, payload }).await;
+    }
+
+    async fn message_private_echo(&mut self, to: String, text_len: usize, payload: Vec<u8>) {
+        self.outbox.write(MessagePrivateEcho { to, text_len, payload }).await;
+    }
+
+    async fn set_private_chat_mode(&mut self, mode: u8) {
+        self.outbox.write(ChatFilterSettingsPrivateChat(mode)).await;
+    }
+
+    async fn update_clan_channel(&mut self, owner: String, name: String, kick_rank: i8, members: Vec<ClanMemberEntry>) {
+        self.outbox.write(UpdateClanChannel { owner, name, kick_rank, members }).await;
+    }
+
+    async fn leave_clan_channel(&mut self) {
+        self.outbox.write(LeaveClanChannel).await;
+    }
+
+    async fn message_clan_channel(&mut self, from: String, channel: String, message_id: u64, rights: u8, text_len: usize, payload: Vec<u8>) {
+        self.outbox.write(MessageClanChannel { from, channel, message_id, rights, text_len, payload }).await;
+    }
+
     async fn loc_add_change(&mut self, zone_frame: ZoneFrame, loc_id: u16, loc_type: u8, rotation: u8, packed_offset: u8) {
         self.outbox.write(LocAddChange { zone_frame, loc_id, loc_type, rotation, packed_offset }).await;
     }
@@ -4,7 +4,7 @@ use macros::player_system;
 use persistence::PlayerData;
 
 use crate::{
-    content::CombatTarget,
+    content::{CombatSpell, CombatTarget},
     player::{
         PlayerSnapshot,
         action::is_action_locked,
@@ -15,6 +15,7 @@ use crate::{
 
 const COMBAT_STYLE_VARP: u16 = 43;
 const AUTO_RETALIATE_VARP: u16 = 172;
+const AUTOCAST_VARP: u16 = 108;
 const SPEC_ENERGY_VARP: u16 = 300;
 const MAX_SPEC_ENERGY: u16 = 1000;
 const SPEC_REGEN_AMOUNT: u16 = 100;
@@ -32,6 +33,8 @@ pub struct CombatManager {
     combat_target: Option<CombatTarget>,
     eat_delay: u16,
     retaliate_target: Option<CombatTarget>,
+    autocast: Option<&'static CombatSpell>,
+    queued_spell: Option<&'static CombatSpell>,
 }
 
 impl CombatManager {
@@ -108,6 +111,26 @@ impl CombatManager {
         }
     }
 
+    pub fn autocast(&self) -> Option<&'static CombatSpell> {
+        self.autocast
+    }
+
+    pub async fn set_autocast(&mut self, spell: Option<&'static CombatSpell>) {
+        self.autocast = spell;
+        self.player
+            .varp_mut()
+            .send_varp(AUTOCAST_VARP, spell.map_or(0, |s| s.autocast))
+            .await;
+    }
+
+    pub fn queue_spell(&mut self, spell: &'static CombatSpell) {
+        self.queued_spell = Some(spell);
+    }
+
+    pub fn take_queued_spell(&mut self) -> Option<&'static CombatSpell> {
+        self.queued_spell.take()
+    }
+
     async fn flush(&mut self) {
         self.player
             .varp_mut()
@@ -141,6 +164,8 @@ impl PlayerSystem for CombatManager {
             combat_target: None,
             eat_delay: 0,
             retaliate_target: None,
+            autocast: None,
+            queued_spell: None,
         }
     }
 
@@ -0,0 +1,66 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use macros::player_system;
+
+use crate::{
+    player::{
+        PlayerSnapshot,
+        system::{PlayerInitContext, PlayerSystem},
+    },
+    world::World,
+};
+
+pub struct ConsumableManager {
+    food_delay: u16,
+    potion_delay: u16,
+    combo_delay: u16,
+}
+
+impl ConsumableManager {
+    pub fn can_eat(&self) -> bool {
+        self.food_delay == 0
+    }
+
+    pub fn can_drink(&self) -> bool {
+        self.potion_delay == 0
+    }
+
+    pub fn can_eat_combo(&self) -> bool {
+        self.combo_delay == 0
+    }
+
+    pub fn set_food_delay(&mut self, ticks: u16) {
+        self.food_delay = self.food_delay.max(ticks);
+    }
+
+    pub fn set_potion_delay(&mut self, ticks: u16) {
+        self.potion_delay = self.potion_delay.max(ticks);
+    }
+
+    pub fn set_combo_delay(&mut self, ticks: u16) {
+        self.combo_delay = self.combo_delay.max(ticks);
+    }
+}
+
+#[player_system]
+impl PlayerSystem for ConsumableManager {
+    type TickContext = ();
+
+    fn create(_ctx: &PlayerInitContext) -> Self {
+        Self {
+            food_delay: 0,
+            potion_delay: 0,
+            combo_delay: 0,
+        }
+    }
+
+    fn tick_context(_: &Arc<World>, _: &PlayerSnapshot) {}
+
+    fn tick<'a>(&'a mut self, _ctx: &'a ()) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async {
+            self.food_delay = self.food_delay.saturating_sub(1);
+            self.potion_delay = self.potion_delay.saturating_sub(1);
+            self.combo_delay = self.combo_delay.saturating_sub(1);
+        })
+    }
+}
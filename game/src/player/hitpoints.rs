@@ -5,7 +5,7 @@ use macros::{player_action, player_system};
 use crate::{
     entity::Hit,
     player::{
-        Clientbound, PlayerSnapshot,
+        Clientbound, PlayerSnapshot, Prayer,
         action::fire_action,
         stat::{Stat, StatManager},
         system::{PlayerHandle, PlayerInitContext, PlayerSystem},
@@ -15,6 +15,9 @@ use crate::{
 
 const DEATH_SEQ: u16 = 836;
 const DEATH_TICKS: u16 = 5;
+const REGEN_TICKS: u16 = 100;
+const RAPID_HEAL_TICKS: u16 = 50;
+const KILLER_TICKS: u32 = 17;
 
 fn respawn() -> Position {
     Position::new(3221, 3219, 0)
@@ -29,10 +32,17 @@ async fn death_action() {
     player.player_info.add_mask(crate::player::FaceEntityMask(65535));
     player.combat_mut().set_combat_target(None);
 
+    let killer = player.hitpoints().last_attacker();
+
     seq!(DEATH_SEQ);
     delay!(DEATH_TICKS);
 
+    crate::content::drop_items_on_death(&mut player, killer).await;
+
     player.hitpoints_mut().revive();
+    player.stat_mut().reset_levels().await;
+    player.prayer_mut().deactivate_all().await;
+    player.prayer_mut().recharge();
     player.interaction_mut().clear();
     player.movement_mut().teleport(respawn()).await;
 
@@ -41,11 +51,22 @@ async fn death_action() {
     unlock!();
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct Attacker {
+    pub index: usize,
+    pub account_id: i64,
+    tick: u32,
+}
+
 pub struct HitpointsManager {
     player: PlayerHandle,
     current: u8,
     dirty: bool,
     dying: bool,
+    regen_timer: u16,
+    decay_timer: u16,
+    ticks: u32,
+    last_attacker: Option<Attacker>,
 }
 
 impl HitpointsManager {
@@ -54,7 +75,7 @@ impl HitpointsManager {
     }
 
     pub fn max(&self) -> u8 {
-        self.player.stat().level(Stat::Hitpoints)
+        self.player.stat().base_level(Stat::Hitpoints)
     }
 
     pub fn is_dying(&self) -> bool {
@@ -73,19 +94,35 @@ impl HitpointsManager {
         self.current == 0
     }
 
+    pub fn set_last_attacker(&mut self, attacker: Option<(usize, i64)>) {
+        self.last_attacker = attacker.map(|(index, account_id)| Attacker {
+            index,
+            account_id,
+            tick: self.ticks,
+        });
+    }
+
+    pub fn last_attacker(&self) -> Option<Attacker> {
+        self.last_attacker
+            .filter(|a| self.ticks.wrapping_sub(a.tick) <= KILLER_TICKS)
+    }
+
     pub fn mark_dirty(&mut self) {
         self.dirty = true;
     }
 
     pub fn heal(&mut self, amount: u8) {
         let max = self.max();
-        self.current = self.current.saturating_add(amount).min(max);
+        if self.current < max {
+            self.current = self.current.saturating_add(amount).min(max);
+        }
         self.dirty = true;
     }
 
     pub fn revive(&mut self) {
         self.current = self.max();
         self.dying = false;
+        self.last_attacker = None;
         self.dirty = true;
     }
 
@@ -99,7 +136,36 @@ impl HitpointsManager {
         self.player.update_stat(Stat::Hitpoints as u8, self.current, xp).await;
     }
 
+    fn regenerate(&mut self) {
+        let max = self.max();
+        let rapid_heal = self.player.prayer().is_active(Prayer::RapidHeal);
+        let regen_ticks = if rapid_heal { RAPID_HEAL_TICKS } else { REGEN_TICKS };
+
+        self.regen_timer += 1;
+        if self.regen_timer >= regen_ticks {
+            self.regen_timer = 0;
+            if self.current < max {
+                self.current += 1;
+                self.dirty = true;
+            }
+        }
+
+        self.decay_timer += 1;
+        if self.decay_timer >= REGEN_TICKS {
+            self.decay_timer = 0;
+            if self.current > max {
+                self.current -= 1;
+                self.dirty = true;
+            }
+        }
+    }
+
     async fn tick_inner(&mut self) {
+        self.ticks = self.ticks.wrapping_add(1);
+        if !self.dying && self.current > 0 {
+            self.regenerate();
+        }
+
         if self.current == 0 && !self.dying {
             self.on_death();
         } else if self.dirty {
@@ -129,6 +195,10 @@ impl PlayerSystem for HitpointsManager {
             current,
             dirty: false,
             dying: false,
+            regen_timer: 0,
+            decay_timer: 0,
+            ticks: 0,
+            last_attacker: None,
         }
     }
 
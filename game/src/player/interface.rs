@@ -6,10 +6,11 @@ use num_enum::TryFromPrimitive;
 use strum::IntoEnumIterator;
 
 use crate::{
+    content::{ALCHEMY_SPELLS, SPELLS},
     player::{
         Clientbound, PlayerSnapshot,
         system::{PlayerHandle, PlayerInitContext, PlayerSystem},
-        ui,
+        ui::{self, tabs},
     },
     world::World,
 };
@@ -292,6 +293,21 @@ impl PlayerSystem for InterfaceManager {
                     .if_set_events(if_set_events!(interface_id: 884, component_id: component, slots: [0xFFFF => 0], right_click[0]))
                     .await;
             }
+
+            for spell in SPELLS {
+                self.player
+                    .if_set_events(if_set_events!(
+                        interface_id: tabs::MAGIC, component_id: spell.component, slots: [0xFFFF => 0], right_click[0], use_on[npcs, players]
+                    ))
+                    .await;
+            }
+            for spell in ALCHEMY_SPELLS {
+                self.player
+                    .if_set_events(if_set_events!(
+                        interface_id: tabs::MAGIC, component_id: spell.component, slots: [0xFFFF => 0], use_on[components]
+                    ))
+                    .await;
+            }
         })
     }
 
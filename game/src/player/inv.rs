@@ -36,16 +36,24 @@ impl Inv {
             .sum()
     }
 
-    pub async fn add(&mut self, obj_id: u16, amount: u32) -> u32 {
-        let remaining = match is_stackable(obj_id) {
-            true => self.add_stackable(obj_id, amount),
-            false => self.add_unstackable(obj_id, amount),
-        };
+    pub fn slots(&self) -> &[Option<Obj>] {
+        &self.slots
+    }
 
+    pub async fn add(&mut self, obj_id: u16, amount: u32) -> u32 {
+        let remaining = self.insert(obj_id, amount);
         self.flush().await;
         remaining
     }
 
+    /// Adds without flushing, for callers that cannot await such as logout.
+    pub fn insert(&mut self, obj_id: u16, amount: u32) -> u32 {
+        match is_stackable(obj_id) {
+            true => self.add_stackable(obj_id, amount),
+            false => self.add_unstackable(obj_id, amount),
+        }
+    }
+
     fn add_stackable(&mut self, obj_id: u16, amount: u32) -> u32 {
         if let Some(obj) = self.slots.iter_mut().flatten().find(|obj| obj.id == obj_id) {
             let added = amount.min(STACK_MAX - obj.amount);
@@ -109,6 +109,7 @@ pub struct AppearanceMask {
     pub colors: [u8; 5],
     pub display_name: String,
     pub combat_level: u8,
+    pub head_icon: u8,
     pub worn: WornSlots,
 }
 
@@ -131,7 +132,7 @@ impl AppearanceMask {
         buf.put_u8(if self.male { 0 } else { 1 });
         buf.put_u8(0);
         buf.put_u8(0xFF);
-        buf.put_u8(0xFF);
+        buf.put_u8(self.head_icon);
 
         let head_flag = self.flag_for(WearPos::Head);
         let hides_arms =
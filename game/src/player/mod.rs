@@ -4,8 +4,10 @@ mod macros;
 mod action;
 mod appearance;
 mod bank;
+mod clan;
 mod clientbound;
 mod combat;
+mod consumable;
 mod countprompt;
 mod dialogue;
 mod gpi;
@@ -17,12 +19,18 @@ mod inv;
 mod loc;
 mod mask;
 mod movement;
+mod mute;
+mod nameprompt;
 mod obj;
 mod objstack;
 mod options;
+mod prayer;
+mod social;
 mod stat;
 mod state;
 mod system;
+mod tolerance;
+mod trade;
 mod ui;
 mod varp;
 mod viewport;
@@ -42,6 +50,7 @@ pub use bank::{PendingX as BankPendingX, SIZE as BANK_SIZE};
 pub use clientbound::Clientbound;
 pub use dialogue::{DialogueEntity, OPTIONS_BASE, OPTIONS_FIRST_COMPONENT};
 pub use gpi::encode_player_info;
+pub use hitpoints::Attacker;
 pub use info::PlayerInfo;
 pub use interaction::{InteractionTarget, resolve as resolve_interaction};
 pub use interface::InterfaceSlot;
@@ -53,9 +62,13 @@ pub use mask::{
 use net::{Inbox, Outbox};
 pub use obj::Obj;
 use persistence::{Account, PlayerData, Rights};
+pub use prayer::Prayer;
+pub use social::{PrivateChat, broadcast_offline, broadcast_status, next_message_id};
 pub use stat::{NUM_STATS, Stat};
 use system::{PlayerHandle, PlayerInitContext, SystemStore};
-pub use ui::{banking, chatbox, equipment};
+use tokio::sync::mpsc;
+pub use trade::{OFFER_INV as TRADE_OFFER_INV, PendingX as TradePendingX, TradeStage, fits as trade_fits};
+pub use ui::{banking, chatbox, clan_chat, equipment, tabs, trading};
 pub use varp::VarpManager;
 pub use viewport::Viewport;
 pub use worn::{SIZE as WORN_SIZE, WornSlots};
@@ -89,6 +102,7 @@ pub struct Player {
 
     pub inbox: Inbox,
     pub outbox: Outbox,
+    pub logging_out: bool,
 
     pub viewport: Viewport,
     pub player_info: Box<PlayerInfo>,
@@ -119,6 +133,7 @@ impl Player {
             rights: account.rights,
             inbox,
             outbox,
+            logging_out: false,
             viewport,
             player_info,
             npc_info,
@@ -167,6 +182,7 @@ impl Player {
             look: [0; 7],
             colors: [0; 5],
             levels: [1; 24],
+            current_levels: [1; 24],
             xp: [0; 24],
             inv: vec![None; INV_SIZE],
             worn: vec![None; WORN_SIZE],
@@ -176,6 +192,11 @@ impl Player {
             auto_retaliate: true,
             spec_energy: 1000,
             current_hp: 10,
+            prayer_points: None,
+            private_chat: 0,
+            friends: Vec::new(),
+            ignores: Vec::new(),
+            clan_channel: None,
         };
 
         self.systems.for_each_persist(&mut data);
@@ -183,14 +204,43 @@ impl Player {
     }
 
     pub async fn on_login(&mut self) {
+        self.init_client().await;
+        self.send_message("Welcome to RuneScape.").await;
+
+        tracing::info!(index = self.index, username = self.username, "Player Logged In");
+    }
+
+    pub async fn on_reconnect(&mut self) {
+        self.init_client().await;
+
+        tracing::info!(index = self.index, username = self.username, "Player Reconnected");
+    }
+
+    async fn init_client(&mut self) {
         self.rebuild_normal(true).await;
 
         let systems = &mut self.systems as *mut SystemStore;
         unsafe { &mut *systems }.on_login(self).await;
+    }
 
-        self.send_message("Welcome to RuneScape.").await;
+    /// Drops the connection's channels so its session ends once queued frames are
+    /// written, running the normal logout even if the client ignores `Logout`.
+    pub fn disconnect(&mut self) {
+        let (_, inbox) = mpsc::channel(1);
+        let (outbox, _) = mpsc::channel(1);
+        self.attach(inbox, outbox, &[]);
+    }
 
-        tracing::info!(index = self.index, username = self.username, "Player Logged In");
+    pub fn attach(&mut self, inbox: Inbox, outbox: Outbox, snapshots: &[PlayerSnapshot]) {
+        let running = self.movement().running;
+        self.npc_info = NpcInfo::new(outbox.clone());
+        self.player_info = PlayerInfo::new(outbox.clone(), self.index, snapshots, &[&MoveTypeMask(running)]);
+        self.viewport = Viewport::new(self.position, 0);
+        self.inbox = inbox;
+        self.outbox = outbox;
+
+        self.obj_stack_mut().known.clear();
+        self.loc_mut().known.clear();
     }
 
     pub async fn tick_movement(&mut self, world: &Arc<World>) {
@@ -236,6 +286,8 @@ impl Player {
         self.combat_mut().set_combat_target(None);
         self.dialogue_mut().close().await;
         self.count_prompt_mut().clear().await;
+        self.name_prompt_mut().clear().await;
+        crate::content::decline_trade(self).await;
         if close_interfaces {
             self.interface_mut().close_slot(InterfaceSlot::Modal).await;
             self.interface_mut().close_slot(InterfaceSlot::Inventory).await;
@@ -114,6 +114,11 @@ impl Movement {
         }
     }
 
+    pub async fn add_run_energy(&mut self, amount: u16) {
+        self.run_energy = (self.run_energy + amount).min(10_000);
+        self.send_run_energy().await;
+    }
+
     async fn restore_energy(&mut self, agility: u8) {
         if self.run_energy >= 10_000 {
             return;
@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+use macros::player_system;
+
+use crate::{
+    player::{
+        PlayerSnapshot,
+        system::{PlayerInitContext, PlayerSystem},
+    },
+    world::World,
+};
+
+#[derive(Clone, Copy)]
+enum Expiry {
+    Never,
+    At(Instant),
+}
+
+pub struct Mute {
+    expiry: Option<Expiry>,
+}
+
+impl Mute {
+    pub fn is_muted(&self) -> bool {
+        match self.expiry {
+            Some(Expiry::Never) => true,
+            Some(Expiry::At(at)) => at > Instant::now(),
+            None => false,
+        }
+    }
+
+    /// Mutes for `duration`, or permanently when `None`.
+    pub fn set(&mut self, duration: Option<Duration>) {
+        self.expiry = Some(match duration {
+            Some(d) => Expiry::At(Instant::now() + d),
+            None => Expiry::Never,
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.expiry = None;
+    }
+}
+
+#[player_system]
+impl PlayerSystem for Mute {
+    type TickContext = ();
+
+    fn create(_ctx: &PlayerInitContext) -> Self {
+        Self { expiry: None }
+    }
+
+    fn tick_context(_: &std::sync::Arc<World>, _: &PlayerSnapshot) {}
+}
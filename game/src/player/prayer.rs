@@ -0,0 +1,414 @@
+use std::{any::TypeId, future::Future, pin::Pin, sync::Arc};
+
+use macros::{enum_data, player_system};
+use net::{if_events, if_set_events};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use persistence::PlayerData;
+
+use crate::{
+    player::{
+        Clientbound, PlayerSnapshot,
+        stat::{Stat, StatManager},
+        system::{PlayerHandle, PlayerInitContext, PlayerSystem},
+        ui::tabs,
+        worn::Worn,
+    },
+    world::World,
+};
+
+const ACTIVE_VARP: u16 = 1395;
+const POINTS_VARP: u16 = 2382;
+const QUICK_ORB_VARC: u16 = 182;
+const PRAYER_LIST_COMPONENT: u16 = 8;
+
+const ATTACK: u8 = 1 << 0;
+const STRENGTH: u8 = 1 << 1;
+const DEFENCE: u8 = 1 << 2;
+const OVERHEAD: u8 = 1 << 3;
+const SUMMONING: u8 = 1 << 4;
+const NONE: u8 = 0;
+
+#[enum_data(discriminant, u8, u32, u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive, strum::IntoStaticStr)]
+#[repr(u8)]
+pub enum Prayer {
+    #[strum(serialize = "Thick Skin")]
+    ThickSkin = (0, 1, 1, DEFENCE),
+    #[strum(serialize = "Burst of Strength")]
+    BurstOfStrength = (1, 4, 1, STRENGTH),
+    #[strum(serialize = "Clarity of Thought")]
+    ClarityOfThought = (2, 7, 1, ATTACK),
+    #[strum(serialize = "Sharp Eye")]
+    SharpEye = (3, 8, 1, ATTACK | STRENGTH),
+    #[strum(serialize = "Mystic Will")]
+    MysticWill = (4, 9, 1, ATTACK | STRENGTH),
+    #[strum(serialize = "Rock Skin")]
+    RockSkin = (5, 10, 6, DEFENCE),
+    #[strum(serialize = "Superhuman Strength")]
+    SuperhumanStrength = (6, 13, 6, STRENGTH),
+    #[strum(serialize = "Improved Reflexes")]
+    ImprovedReflexes = (7, 16, 6, ATTACK),
+    #[strum(serialize = "Rapid Restore")]
+    RapidRestore = (8, 19, 1, NONE),
+    #[strum(serialize = "Rapid Heal")]
+    RapidHeal = (9, 22, 2, NONE),
+    #[strum(serialize = "Protect Item")]
+    ProtectItem = (10, 25, 2, NONE),
+    #[strum(serialize = "Hawk Eye")]
+    HawkEye = (11, 26, 6, ATTACK | STRENGTH),
+    #[strum(serialize = "Mystic Lore")]
+    MysticLore = (12, 27, 6, ATTACK | STRENGTH),
+    #[strum(serialize = "Steel Skin")]
+    SteelSkin = (13, 28, 12, DEFENCE),
+    #[strum(serialize = "Ultimate Strength")]
+    UltimateStrength = (14, 31, 12, STRENGTH),
+    #[strum(serialize = "Incredible Reflexes")]
+    IncredibleReflexes = (15, 34, 12, ATTACK),
+    #[strum(serialize = "Protect from Summoning")]
+    ProtectFromSummoning = (16, 35, 12, SUMMONING),
+    #[strum(serialize = "Protect from Magic")]
+    ProtectFromMagic = (17, 37, 12, OVERHEAD),
+    #[strum(serialize = "Protect from Missiles")]
+    ProtectFromMissiles = (18, 40, 12, OVERHEAD),
+    #[strum(serialize = "Protect from Melee")]
+    ProtectFromMelee = (19, 43, 12, OVERHEAD),
+    #[strum(serialize = "Eagle Eye")]
+    EagleEye = (20, 44, 12, ATTACK | STRENGTH),
+    #[strum(serialize = "Mystic Might")]
+    MysticMight = (21, 45, 12, ATTACK | STRENGTH),
+    Retribution = (22, 46, 3, OVERHEAD | SUMMONING),
+    Redemption = (23, 49, 6, OVERHEAD | SUMMONING),
+    Smite = (24, 52, 18, OVERHEAD | SUMMONING),
+    Chivalry = (25, 60, 24, ATTACK | STRENGTH | DEFENCE),
+    Piety = (26, 70, 24, ATTACK | STRENGTH | DEFENCE),
+}
+
+impl Prayer {
+    pub fn level(self) -> u8 {
+        self.data().0
+    }
+
+    pub fn drain_effect(self) -> u32 {
+        self.data().1
+    }
+
+    fn groups(self) -> u8 {
+        self.data().2
+    }
+
+    fn bit(self) -> u32 {
+        1 << u8::from(self)
+    }
+
+    pub fn attack_multiplier(self) -> f64 {
+        match self {
+            Self::ClarityOfThought => 1.05,
+            Self::ImprovedReflexes => 1.10,
+            Self::IncredibleReflexes | Self::Chivalry => 1.15,
+            Self::Piety => 1.20,
+            _ => 1.0,
+        }
+    }
+
+    pub fn strength_multiplier(self) -> f64 {
+        match self {
+            Self::BurstOfStrength => 1.05,
+            Self::SuperhumanStrength => 1.10,
+            Self::UltimateStrength => 1.15,
+            Self::Chivalry => 1.18,
+            Self::Piety => 1.23,
+            _ => 1.0,
+        }
+    }
+
+    pub fn defence_multiplier(self) -> f64 {
+        match self {
+            Self::ThickSkin => 1.05,
+            Self::RockSkin => 1.10,
+            Self::SteelSkin => 1.15,
+            Self::Chivalry => 1.20,
+            Self::Piety => 1.25,
+            _ => 1.0,
+        }
+    }
+
+    pub fn ranged_multiplier(self) -> f64 {
+        match self {
+            Self::SharpEye => 1.05,
+            Self::HawkEye => 1.10,
+            Self::EagleEye => 1.15,
+            _ => 1.0,
+        }
+    }
+
+    pub fn magic_multiplier(self) -> f64 {
+        match self {
+            Self::MysticWill => 1.05,
+            Self::MysticLore => 1.10,
+            Self::MysticMight => 1.15,
+            _ => 1.0,
+        }
+    }
+}
+
+const NUM_PRAYERS: u8 = Prayer::Piety as u8 + 1;
+
+pub struct PrayerManager {
+    player: PlayerHandle,
+    points: u8,
+    active: u32,
+    quick: u32,
+    drain_counter: u32,
+    dirty: bool,
+}
+
+impl PrayerManager {
+    pub fn points(&self) -> u8 {
+        self.points
+    }
+
+    pub fn max(&self) -> u8 {
+        self.player.stat().base_level(Stat::Prayer)
+    }
+
+    pub fn is_active(&self, prayer: Prayer) -> bool {
+        self.active & prayer.bit() != 0
+    }
+
+    pub fn active(&self) -> impl Iterator<Item = Prayer> + '_ {
+        (0..NUM_PRAYERS)
+            .filter_map(|i| Prayer::try_from(i).ok())
+            .filter(|p| self.is_active(*p))
+    }
+
+    pub fn multiplier(&self, f: fn(Prayer) -> f64) -> f64 {
+        self.active().map(f).fold(1.0, f64::max)
+    }
+
+    pub fn head_icon(&self) -> u8 {
+        let summoning = self.is_active(Prayer::ProtectFromSummoning);
+        let overhead = if self.is_active(Prayer::ProtectFromMelee) {
+            Some(0)
+        } else if self.is_active(Prayer::ProtectFromMissiles) {
+            Some(1)
+        } else if self.is_active(Prayer::ProtectFromMagic) {
+            Some(2)
+        } else if self.is_active(Prayer::Retribution) {
+            Some(3)
+        } else if self.is_active(Prayer::Smite) {
+            Some(4)
+        } else if self.is_active(Prayer::Redemption) {
+            Some(5)
+        } else {
+            None
+        };
+
+        match (overhead, summoning) {
+            (Some(icon @ 0..=2), true) => icon + 8,
+            (None, true) => 7,
+            (Some(icon), _) => icon,
+            (None, false) => 0xFF,
+        }
+    }
+
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    pub fn restore(&mut self, amount: u8) {
+        self.points = self.points.saturating_add(amount).min(self.max());
+        self.dirty = true;
+    }
+
+    pub fn recharge(&mut self) -> bool {
+        let max = self.max();
+        if self.points >= max {
+            return false;
+        }
+        self.points = max;
+        self.dirty = true;
+        true
+    }
+
+    pub fn drain(&mut self, amount: u8) {
+        self.points = self.points.saturating_sub(amount);
+        self.dirty = true;
+    }
+
+    pub async fn toggle(&mut self, prayer: Prayer) {
+        if self.is_active(prayer) {
+            self.set_active(self.active & !prayer.bit()).await;
+            return;
+        }
+
+        if self.player.stat().level(Stat::Prayer) < prayer.level() {
+            let name: &str = prayer.into();
+            self.player
+                .send_message(format!(
+                    "You need a Prayer level of {} to use {}.",
+                    prayer.level(),
+                    name
+                ))
+                .await;
+            self.send_active().await;
+            return;
+        }
+
+        if self.points == 0 {
+            self.player
+                .send_message("You need to recharge your Prayer at an altar.")
+                .await;
+            self.send_active().await;
+            return;
+        }
+
+        let conflicts = self
+            .active()
+            .filter(|p| p.groups() & prayer.groups() != 0)
+            .fold(0, |acc, p| acc | p.bit());
+        self.set_active((self.active & !conflicts) | prayer.bit()).await;
+    }
+
+    pub async fn toggle_quick(&mut self) {
+        if self.active != 0 {
+            self.quick = self.active;
+            self.deactivate_all().await;
+            return;
+        }
+
+        if self.points == 0 {
+            self.player
+                .send_message("You need to recharge your Prayer at an altar.")
+                .await;
+            return;
+        }
+
+        let level = self.player.stat().level(Stat::Prayer);
+        let usable = (0..NUM_PRAYERS)
+            .filter_map(|i| Prayer::try_from(i).ok())
+            .filter(|p| self.quick & p.bit() != 0 && p.level() <= level)
+            .fold(0, |acc, p| acc | p.bit());
+        if usable == 0 {
+            self.player
+                .send_message("You haven't selected any quick-prayers.")
+                .await;
+            return;
+        }
+        self.set_active(usable).await;
+    }
+
+    pub async fn deactivate_all(&mut self) {
+        self.set_active(0).await;
+    }
+
+    async fn set_active(&mut self, active: u32) {
+        let head_icon = self.head_icon();
+        self.active = active;
+        if self.active == 0 {
+            self.drain_counter = 0;
+        }
+        self.send_active().await;
+        if self.head_icon() != head_icon {
+            self.player.appearance_mut().flush();
+        }
+    }
+
+    async fn send_active(&mut self) {
+        let active = self.active as i32;
+        self.player.varp_mut().send_varp(ACTIVE_VARP, active).await;
+        self.player
+            .varp_mut()
+            .send_varc(QUICK_ORB_VARC, (active != 0) as i32)
+            .await;
+    }
+
+    fn drain_resistance(&self) -> u32 {
+        let bonus = self.player.worn().bonuses().prayer as i32;
+        (60 + bonus * 2).max(1) as u32
+    }
+
+    async fn flush(&mut self) {
+        let xp = self.player.stat().xp(Stat::Prayer);
+        self.player.update_stat(Stat::Prayer as u8, self.points, xp).await;
+        self.player
+            .varp_mut()
+            .send_varp(POINTS_VARP, self.points as i32 * 10)
+            .await;
+    }
+
+    async fn tick_inner(&mut self) {
+        if self.active != 0 {
+            self.drain_counter += self.active().map(Prayer::drain_effect).sum::<u32>();
+            let resistance = self.drain_resistance();
+            while self.drain_counter > resistance && self.points > 0 {
+                self.drain_counter -= resistance;
+                self.drain(1);
+            }
+
+            if self.points == 0 {
+                self.deactivate_all().await;
+                self.player
+                    .send_message("You have run out of Prayer points; you can recharge at an altar.")
+                    .await;
+            }
+        }
+
+        if self.dirty {
+            self.dirty = false;
+            self.flush().await;
+        }
+    }
+}
+
+#[player_system]
+impl PlayerSystem for PrayerManager {
+    type TickContext = ();
+
+    fn dependencies() -> Vec<TypeId> {
+        vec![TypeId::of::<StatManager>(), TypeId::of::<Worn>()]
+    }
+
+    fn create(ctx: &PlayerInitContext) -> Self {
+        let points = ctx
+            .player_data
+            .prayer_points
+            .unwrap_or(ctx.player_data.levels[Stat::Prayer as usize]);
+
+        Self {
+            player: ctx.player,
+            points,
+            active: 0,
+            quick: 0,
+            drain_counter: 0,
+            dirty: false,
+        }
+    }
+
+    fn on_login<'a>(
+        &'a mut self,
+        _player: &'a mut crate::player::Player,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            self.flush().await;
+            self.send_active().await;
+            self.player
+                .if_set_events(if_set_events!(
+                    interface_id: tabs::PRAYER,
+                    component_id: PRAYER_LIST_COMPONENT,
+                    slots: [0 => NUM_PRAYERS as u16 - 1],
+                    right_click[0]
+                ))
+                .await;
+        })
+    }
+
+    fn tick_context(_: &Arc<World>, _: &PlayerSnapshot) {}
+
+    fn tick<'a>(&'a mut self, _ctx: &'a ()) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(self.tick_inner())
+    }
+
+    fn persist(&self, data: &mut PlayerData) {
+        data.prayer_points = Some(self.points);
+    }
+}
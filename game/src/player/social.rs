@@ -0,0 +1,248 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use macros::player_system;
+use net::FriendEntry;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use persistence::PlayerData;
+use util::{format_display_name, normalize_name};
+
+use crate::{
+    player::{
+        Clientbound, Player, PlayerSnapshot,
+        system::{PlayerHandle, PlayerInitContext, PlayerSystem},
+    },
+    world::World,
+};
+
+pub const MAX_FRIENDS: usize = 200;
+pub const MAX_IGNORES: usize = 100;
+const MAX_NAME_LEN: usize = 12;
+
+static MESSAGE_ID: AtomicU64 = AtomicU64::new(1);
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+pub enum PrivateChat {
+    On = 0,
+    Friends = 1,
+    Off = 2,
+}
+
+pub struct Social {
+    player: PlayerHandle,
+    friends: Vec<String>,
+    ignores: Vec<String>,
+    private_chat: PrivateChat,
+}
+
+impl Social {
+    pub fn private_chat(&self) -> PrivateChat {
+        self.private_chat
+    }
+
+    pub fn friends(&self) -> &[String] {
+        &self.friends
+    }
+
+    pub fn has_friend(&self, name: &str) -> bool {
+        self.friends.iter().any(|f| f == name)
+    }
+
+    pub fn is_ignoring(&self, name: &str) -> bool {
+        self.ignores.iter().any(|i| i == name)
+    }
+
+    pub fn is_visible_to(&self, viewer: &str) -> bool {
+        if self.is_ignoring(viewer) {
+            return false;
+        }
+        match self.private_chat {
+            PrivateChat::On => true,
+            PrivateChat::Friends => self.has_friend(viewer),
+            PrivateChat::Off => false,
+        }
+    }
+
+    pub async fn set_private_chat(&mut self, mode: PrivateChat) {
+        self.private_chat = mode;
+        self.player.set_private_chat_mode(mode.into()).await;
+    }
+
+    pub async fn add_friend(&mut self, name: &str) -> bool {
+        let name = normalize_name(name);
+        if !is_valid_name(&name) || name == normalize_name(&self.player.username) {
+            return false;
+        }
+        if self.has_friend(&name) {
+            let msg = format!("{} is already on your friend list.", format_display_name(&name));
+            self.player.send_message(msg).await;
+            return false;
+        }
+        if self.is_ignoring(&name) {
+            let msg = format!(
+                "Please remove {} from your ignore list first.",
+                format_display_name(&name)
+            );
+            self.player.send_message(msg).await;
+            return false;
+        }
+        if self.friends.len() >= MAX_FRIENDS {
+            self.player.send_message("Your friend list is full.").await;
+            return false;
+        }
+
+        let world = self.player.world();
+        let status = friend_world(&world, &name, &self.player);
+        self.friends.push(name.clone());
+        self.send_friend(&name, status).await;
+        true
+    }
+
+    pub fn remove_friend(&mut self, name: &str) -> bool {
+        let name = normalize_name(name);
+        let before = self.friends.len();
+        self.friends.retain(|f| *f != name);
+        self.friends.len() != before
+    }
+
+    pub async fn add_ignore(&mut self, name: &str) -> bool {
+        let name = normalize_name(name);
+        if !is_valid_name(&name) || name == normalize_name(&self.player.username) || self.is_ignoring(&name) {
+            return false;
+        }
+        if self.has_friend(&name) {
+            let msg = format!(
+                "Please remove {} from your friend list first.",
+                format_display_name(&name)
+            );
+            self.player.send_message(msg).await;
+            return false;
+        }
+        if self.ignores.len() >= MAX_IGNORES {
+            self.player.send_message("Your ignore list is full.").await;
+            return false;
+        }
+
+        self.ignores.push(name);
+        self.send_ignores().await;
+        true
+    }
+
+    pub async fn remove_ignore(&mut self, name: &str) -> bool {
+        let name = normalize_name(name);
+        let before = self.ignores.len();
+        self.ignores.retain(|i| *i != name);
+        if self.ignores.len() == before {
+            return false;
+        }
+        self.send_ignores().await;
+        true
+    }
+
+    pub async fn send_friend(&mut self, name: &str, world: u16) {
+        let friends = vec![FriendEntry {
+            name: format_display_name(name),
+            world,
+        }];
+        self.player.update_friend_list(friends).await;
+    }
+
+    pub async fn send_friends(&mut self) {
+        let world = self.player.world();
+        let friends = self
+            .friends
+            .iter()
+            .map(|name| FriendEntry {
+                name: format_display_name(name),
+                world: friend_world(&world, name, &self.player),
+            })
+            .collect();
+        self.player.update_friend_list(friends).await;
+    }
+
+    async fn send_ignores(&mut self) {
+        let names = self.ignores.iter().map(|n| format_display_name(n)).collect();
+        self.player.update_ignore_list(names).await;
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    (1..=MAX_NAME_LEN).contains(&name.len()) && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+pub fn next_message_id() -> u64 {
+    MESSAGE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+fn friend_world(world: &World, name: &str, viewer: &Player) -> u16 {
+    let Some(index) = world.find_player(name).filter(|&i| i != viewer.index) else {
+        return 0;
+    };
+    let visible = world
+        .player(index)
+        .social()
+        .is_visible_to(&normalize_name(&viewer.username));
+    if visible { world.world_id() } else { 0 }
+}
+
+pub async fn broadcast_status(world: &World, player: &Player, online: bool) {
+    let name = normalize_name(&player.username);
+    for index in world.players.keys() {
+        if index == player.index || !world.players.contains(index) {
+            continue;
+        }
+        let mut other = world.player_mut(index);
+        if !other.social().has_friend(&name) {
+            continue;
+        }
+        let visible = online && player.social().is_visible_to(&normalize_name(&other.username));
+        let status = if visible { world.world_id() } else { 0 };
+        other.social_mut().send_friend(&name, status).await;
+    }
+}
+
+#[player_system]
+impl PlayerSystem for Social {
+    type TickContext = ();
+
+    fn create(ctx: &PlayerInitContext) -> Self {
+        Self {
+            player: ctx.player,
+            friends: ctx.player_data.friends.clone(),
+            ignores: ctx.player_data.ignores.clone(),
+            private_chat: PrivateChat::try_from(ctx.player_data.private_chat).unwrap_or(PrivateChat::On),
+        }
+    }
+
+    fn on_login<'a>(&'a mut self, _player: &'a mut Player) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let mode = self.private_chat.into();
+            self.player.set_private_chat_mode(mode).await;
+            self.send_ignores().await;
+        })
+    }
+
+    fn tick_context(_: &std::sync::Arc<World>, _: &PlayerSnapshot) {}
+
+    fn persist(&self, data: &mut PlayerData) {
+        data.private_chat = self.private_chat.into();
+        data.friends = self.friends.clone();
+        data.ignores = self.ignores.clone();
+    }
+}
+
+pub async fn broadcast_offline(world: &World, player_index: usize, name: &str) {
+    for index in world.players.keys() {
+        if index == player_index || !world.players.contains(index) {
+            continue;
+        }
+        let mut other = world.player_mut(index);
+        if other.social().has_friend(name) {
+            other.social_mut().send_friend(name, 0).await;
+        }
+    }
+}
@@ -6,7 +6,7 @@ use persistence::PlayerData;
 
 use crate::{
     player::{
-        Clientbound, PlayerSnapshot, chatbox,
+        Clientbound, PlayerSnapshot, Prayer, chatbox,
         system::{PlayerHandle, PlayerInitContext, PlayerSystem},
     },
     world::World,
@@ -72,10 +72,16 @@ impl Stat {
 
 pub const NUM_STATS: usize = 24;
 
+const RESTORE_TICKS: u16 = 100;
+const RAPID_RESTORE_TICKS: u16 = 50;
+
 pub struct StatManager {
     player: PlayerHandle,
     levels: [u8; NUM_STATS],
+    current: [u8; NUM_STATS],
     xp: [u32; NUM_STATS],
+    restore_timer: u16,
+    decay_timer: u16,
 }
 
 impl StatManager {
@@ -83,11 +89,19 @@ impl StatManager {
         self.levels
     }
 
+    pub fn current_levels(&self) -> [u8; NUM_STATS] {
+        self.current
+    }
+
     pub fn xp_values(&self) -> [u32; NUM_STATS] {
         self.xp
     }
 
     pub fn level(&self, stat: Stat) -> u8 {
+        self.current[stat as usize]
+    }
+
+    pub fn base_level(&self, stat: Stat) -> u8 {
         self.levels[stat as usize]
     }
 
@@ -98,6 +112,7 @@ impl StatManager {
     pub fn set_level(&mut self, stat: Stat, level: u8) {
         let i = stat as usize;
         self.levels[i] = level;
+        self.current[i] = level;
         self.xp[i] = xp_for_level(level);
     }
 
@@ -105,6 +120,37 @@ impl StatManager {
         let i = stat as usize;
         self.xp[i] = xp;
         self.levels[i] = level_for_xp(xp);
+        self.current[i] = self.levels[i];
+    }
+
+    pub async fn boost(&mut self, stat: Stat, amount: u8) {
+        let i = stat as usize;
+        let boosted = self.levels[i].saturating_add(amount);
+        if self.current[i] < boosted {
+            self.current[i] = boosted;
+            self.send_stat(stat).await;
+        }
+    }
+
+    pub async fn drain(&mut self, stat: Stat, amount: u8) {
+        let i = stat as usize;
+        self.current[i] = self.current[i].saturating_sub(amount);
+        self.send_stat(stat).await;
+    }
+
+    pub async fn restore(&mut self, stat: Stat, amount: u8) {
+        let i = stat as usize;
+        if self.current[i] < self.levels[i] {
+            self.current[i] = self.current[i].saturating_add(amount).min(self.levels[i]);
+            self.send_stat(stat).await;
+        }
+    }
+
+    pub async fn reset_levels(&mut self) {
+        self.current = self.levels;
+        self.restore_timer = 0;
+        self.decay_timer = 0;
+        self.flush().await;
     }
 
     pub async fn add_xp(&mut self, stat: Stat, xp: f64) {
@@ -112,11 +158,12 @@ impl StatManager {
         let old_level = self.levels[i];
         self.xp[i] = self.xp[i].saturating_add(xp as u32);
         self.levels[i] = level_for_xp(self.xp[i]);
+        self.current[i] = self.current[i].saturating_add(self.levels[i] - old_level);
 
-        if stat == Stat::Hitpoints {
-            self.player.hitpoints_mut().mark_dirty();
-        } else {
-            self.send_stat(stat).await;
+        match stat {
+            Stat::Hitpoints => self.player.hitpoints_mut().mark_dirty(),
+            Stat::Prayer => self.player.prayer_mut().mark_dirty(),
+            _ => self.send_stat(stat).await,
         }
 
         if self.levels[i] > old_level {
@@ -161,7 +208,7 @@ impl StatManager {
     pub async fn flush(&mut self) {
         for i in 0..NUM_STATS {
             let Some(stat) = Stat::try_from(i).ok() else { continue };
-            if stat == Stat::Hitpoints {
+            if matches!(stat, Stat::Hitpoints | Stat::Prayer) {
                 continue;
             }
             self.send_stat(stat).await;
@@ -169,11 +216,43 @@ impl StatManager {
     }
 
     pub async fn send_stat(&mut self, stat: Stat) {
-        if stat == Stat::Hitpoints {
+        if matches!(stat, Stat::Hitpoints | Stat::Prayer) {
             return;
         }
         let i: usize = stat.into();
-        self.player.update_stat(i as u8, self.levels[i], self.xp[i]).await;
+        self.player.update_stat(i as u8, self.current[i], self.xp[i]).await;
+    }
+
+    async fn tick_inner(&mut self) {
+        let rapid_restore = self.player.prayer().is_active(Prayer::RapidRestore);
+        let restore_ticks = if rapid_restore { RAPID_RESTORE_TICKS } else { RESTORE_TICKS };
+
+        self.restore_timer += 1;
+        if self.restore_timer >= restore_ticks {
+            self.restore_timer = 0;
+            self.step_levels(|current, base| current < base).await;
+        }
+
+        self.decay_timer += 1;
+        if self.decay_timer >= RESTORE_TICKS {
+            self.decay_timer = 0;
+            self.step_levels(|current, base| current > base).await;
+        }
+    }
+
+    async fn step_levels(&mut self, applies: impl Fn(u8, u8) -> bool) {
+        for i in 0..NUM_STATS {
+            let Some(stat) = Stat::try_from(i).ok() else { continue };
+            if matches!(stat, Stat::Hitpoints | Stat::Prayer) || !applies(self.current[i], self.levels[i]) {
+                continue;
+            }
+            if self.current[i] < self.levels[i] {
+                self.current[i] += 1;
+            } else {
+                self.current[i] -= 1;
+            }
+            self.send_stat(stat).await;
+        }
     }
 }
 
@@ -202,7 +281,10 @@ impl PlayerSystem for StatManager {
         Self {
             player: ctx.player,
             levels: ctx.player_data.levels,
+            current: ctx.player_data.current_levels,
             xp: ctx.player_data.xp,
+            restore_timer: 0,
+            decay_timer: 0,
         }
     }
 
@@ -215,8 +297,13 @@ impl PlayerSystem for StatManager {
 
     fn tick_context(_: &std::sync::Arc<World>, _: &PlayerSnapshot) {}
 
+    fn tick<'a>(&'a mut self, _ctx: &'a ()) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(self.tick_inner())
+    }
+
     fn persist(&self, data: &mut PlayerData) {
         data.levels = self.levels();
+        data.current_levels = self.current_levels();
         data.xp = self.xp_values();
     }
 }
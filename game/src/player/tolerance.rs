@@ -0,0 +1,47 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use macros::player_system;
+
+use crate::{
+    player::{
+        PlayerSnapshot,
+        system::{PlayerInitContext, PlayerSystem},
+    },
+    world::{Position, RegionId, World},
+};
+
+pub struct ToleranceTracker {
+    region: Option<RegionId>,
+    ticks: u32,
+}
+
+impl ToleranceTracker {
+    pub fn is_tolerated(&self, tolerance_ticks: u16) -> bool {
+        tolerance_ticks > 0 && self.ticks >= tolerance_ticks as u32
+    }
+}
+
+#[player_system]
+impl PlayerSystem for ToleranceTracker {
+    type TickContext = Position;
+
+    fn create(_ctx: &PlayerInitContext) -> Self {
+        Self { region: None, ticks: 0 }
+    }
+
+    fn tick_context(_: &Arc<World>, snapshot: &PlayerSnapshot) -> Position {
+        snapshot.position
+    }
+
+    fn tick<'a>(&'a mut self, position: &'a Position) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let region = position.region_id();
+            if self.region == Some(region) {
+                self.ticks = self.ticks.saturating_add(1);
+            } else {
+                self.region = Some(region);
+                self.ticks = 0;
+            }
+        })
+    }
+}
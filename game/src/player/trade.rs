@@ -0,0 +1,224 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use macros::player_system;
+use net::{InvEntry, InvType};
+
+use crate::{
+    player::{
+        Clientbound, INV_SIZE, Obj, PlayerSnapshot, STACK_MAX,
+        system::{PlayerHandle, PlayerInitContext, PlayerSystem},
+    },
+    provider,
+    world::World,
+};
+
+pub const OFFER_INV: u16 = 90;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TradeStage {
+    Offer,
+    Confirm,
+}
+
+#[derive(Clone, Copy)]
+pub struct PendingX {
+    pub slot: usize,
+    pub offer: bool,
+}
+
+pub struct Trade {
+    player: PlayerHandle,
+    requested: Option<usize>,
+    partner: Option<usize>,
+    stage: TradeStage,
+    accepted: bool,
+    offer: [Option<Obj>; INV_SIZE],
+    pending_x: Option<PendingX>,
+}
+
+impl Trade {
+    pub fn requested(&self) -> Option<usize> {
+        self.requested
+    }
+
+    pub fn set_requested(&mut self, index: Option<usize>) {
+        self.requested = index;
+    }
+
+    pub fn partner(&self) -> Option<usize> {
+        self.partner
+    }
+
+    pub fn stage(&self) -> TradeStage {
+        self.stage
+    }
+
+    pub fn set_stage(&mut self, stage: TradeStage) {
+        self.stage = stage;
+        self.accepted = false;
+    }
+
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+
+    pub fn set_accepted(&mut self, accepted: bool) {
+        self.accepted = accepted;
+    }
+
+    pub fn set_pending_x(&mut self, pending: PendingX) {
+        self.pending_x = Some(pending);
+    }
+
+    pub fn take_pending_x(&mut self) -> Option<PendingX> {
+        self.pending_x.take()
+    }
+
+    pub fn begin(&mut self, partner: usize) {
+        self.requested = None;
+        self.partner = Some(partner);
+        self.stage = TradeStage::Offer;
+        self.accepted = false;
+        self.offer = [None; INV_SIZE];
+    }
+
+    pub fn end(&mut self) -> Vec<Obj> {
+        self.partner = None;
+        self.stage = TradeStage::Offer;
+        self.accepted = false;
+        self.pending_x = None;
+        self.offer.iter_mut().filter_map(Option::take).collect()
+    }
+
+    pub fn slot(&self, index: usize) -> Option<Obj> {
+        self.offer.get(index).copied().flatten()
+    }
+
+    pub fn offered(&self) -> impl Iterator<Item = Obj> + '_ {
+        self.offer.iter().flatten().copied()
+    }
+
+    pub fn value(&self) -> u64 {
+        self.offered()
+            .map(|obj| provider::get_obj_value(obj.id as u32).max(0) as u64 * obj.amount as u64)
+            .sum()
+    }
+
+    pub fn add(&mut self, obj_id: u16, amount: u32) {
+        if is_stackable(obj_id) {
+            if let Some(obj) = self.offer.iter_mut().flatten().find(|obj| obj.id == obj_id) {
+                obj.amount = obj.amount.saturating_add(amount).min(STACK_MAX);
+            } else if let Some(slot) = self.offer.iter_mut().find(|s| s.is_none()) {
+                *slot = Some(Obj::new(obj_id, amount));
+            }
+            return;
+        }
+
+        self.offer
+            .iter_mut()
+            .filter(|s| s.is_none())
+            .take(amount as usize)
+            .for_each(|s| *s = Some(Obj::new(obj_id, 1)));
+    }
+
+    pub fn remove(&mut self, slot: usize, amount: u32) -> Option<Obj> {
+        let obj = self.slot(slot)?;
+        if is_stackable(obj.id) {
+            let taken = amount.min(obj.amount);
+            self.offer[slot] = (taken < obj.amount).then(|| Obj::new(obj.id, obj.amount - taken));
+            return Some(Obj::new(obj.id, taken));
+        }
+
+        let mut taken = 0;
+        for i in std::iter::once(slot).chain((0..INV_SIZE).filter(|&i| i != slot)) {
+            if taken == amount {
+                break;
+            }
+            if self.offer[i].is_some_and(|o| o.id == obj.id) {
+                self.offer[i] = None;
+                taken += 1;
+            }
+        }
+        Some(Obj::new(obj.id, taken))
+    }
+
+    pub fn entries(&self) -> Vec<Option<InvEntry>> {
+        self.offer
+            .iter()
+            .map(|s| {
+                s.map(|obj| InvEntry {
+                    obj_id: obj.id,
+                    amount: obj.amount,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn flush(&mut self) {
+        let entries = self.entries();
+        self.player.update_inv(InvType::Custom(OFFER_INV), false, entries).await;
+    }
+}
+
+pub fn fits(inv: &[Option<Obj>], incoming: impl Iterator<Item = Obj>) -> bool {
+    let mut free = inv.iter().filter(|s| s.is_none()).count();
+    for obj in incoming {
+        if !is_stackable(obj.id) {
+            if free == 0 {
+                return false;
+            }
+            free -= 1;
+            continue;
+        }
+
+        match inv.iter().flatten().find(|o| o.id == obj.id) {
+            Some(existing) if existing.amount as u64 + obj.amount as u64 > STACK_MAX as u64 => return false,
+            Some(_) => {}
+            None if free == 0 => return false,
+            None => free -= 1,
+        }
+    }
+    true
+}
+
+fn is_stackable(obj_id: u16) -> bool {
+    provider::get_obj_type(obj_id as u32).is_some_and(|t| t.stackable)
+}
+
+#[player_system]
+impl PlayerSystem for Trade {
+    type TickContext = Arc<World>;
+
+    fn create(ctx: &PlayerInitContext) -> Self {
+        Self {
+            player: ctx.player,
+            requested: None,
+            partner: None,
+            stage: TradeStage::Offer,
+            accepted: false,
+            offer: [None; INV_SIZE],
+            pending_x: None,
+        }
+    }
+
+    fn tick_context(world: &Arc<World>, _: &PlayerSnapshot) -> Arc<World> {
+        world.clone()
+    }
+
+    fn tick<'a>(&'a mut self, world: &'a Arc<World>) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let Some(partner) = self.partner else { return };
+            let index = self.player.index;
+            let trading = world.players.contains(partner) && world.player(partner).trade().partner() == Some(index);
+            if trading {
+                return;
+            }
+
+            for obj in self.end() {
+                self.player.inv_mut().add(obj.id, obj.amount).await;
+            }
+            crate::content::close_trade_interfaces(&mut self.player).await;
+            self.player.send_message("Other player declined trade.").await;
+        })
+    }
+}
@@ -45,3 +45,13 @@ pub mod banking {
     pub const MAIN: u16 = 762;
     pub const INV: u16 = 763;
 }
+
+pub mod trading {
+    pub const OFFER: u16 = 335;
+    pub const CONFIRM: u16 = 334;
+    pub const INV: u16 = 336;
+}
+
+pub mod clan_chat {
+    pub const SETUP: u16 = 590;
+}
@@ -3,14 +3,20 @@ use std::collections::HashMap;
 use filesystem::{NpcLoader, NpcType};
 use macros::data_provider;
 use once_cell::sync::OnceCell;
-use persistence::{FaceDirection, NpcConfigRepository};
+use persistence::{DropCategory, FaceDirection, NpcConfigRepository};
 use shaku::HasComponent;
 
-use crate::{npc::NpcCombat, provider::ProviderContext, world::Direction};
+use crate::{
+    npc::{DropEntry, DropItem, DropTable, NpcCombat},
+    provider::ProviderContext,
+    world::Direction,
+};
 
 static INSTANCE: OnceCell<NpcLoader> = OnceCell::new();
 static CONFIGS: OnceCell<HashMap<u32, NpcCombat>> = OnceCell::new();
 static SPAWNS: OnceCell<Vec<NpcSpawnDef>> = OnceCell::new();
+static DROPS: OnceCell<HashMap<u32, DropTable>> = OnceCell::new();
+static SHARED_DROPS: OnceCell<HashMap<String, Vec<DropEntry>>> = OnceCell::new();
 
 pub struct NpcSpawnDef {
     pub npc_id: u16,
@@ -56,6 +62,9 @@ async fn load_npc_configs(ctx: &ProviderContext) -> anyhow::Result<()> {
                     death_seq: c.death_seq,
                     max_hit: c.max_hit,
                     atk_range: c.atk_range,
+                    aggro_radius: c.aggro_radius,
+                    tolerance_ticks: c.tolerance_ticks,
+                    always_aggressive: c.always_aggressive,
                 },
             )
         })
@@ -79,6 +88,65 @@ async fn load_npc_configs(ctx: &ProviderContext) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[data_provider]
+async fn load_npc_drops(ctx: &ProviderContext) -> anyhow::Result<()> {
+    let repo: &dyn NpcConfigRepository = ctx.persistence.resolve_ref();
+    let drops = repo.find_all_drops().await?;
+    let shared = repo.find_all_shared_drops().await?;
+
+    let mut tables: HashMap<u32, DropTable> = HashMap::new();
+    for d in drops {
+        let Some(item) = drop_item(d.obj_id, d.shared_table, d.min_amount, d.max_amount, d.noted) else {
+            tracing::warn!(npc_id = d.npc_id, "Drop has neither an obj nor a shared table");
+            continue;
+        };
+        let entry = DropEntry {
+            item,
+            numerator: d.numerator,
+            denominator: d.denominator,
+        };
+        let table = tables.entry(d.npc_id).or_default();
+        match d.category {
+            DropCategory::Always => table.always.push(entry),
+            DropCategory::Main => table.main.push(entry),
+            DropCategory::Tertiary => table.tertiary.push(entry),
+        }
+    }
+
+    let mut shared_tables: HashMap<String, Vec<DropEntry>> = HashMap::new();
+    for d in shared {
+        let Some(item) = drop_item(d.obj_id, d.shared_table, d.min_amount, d.max_amount, d.noted) else {
+            tracing::warn!(
+                table = d.table_name,
+                "Shared drop has neither an obj nor a shared table"
+            );
+            continue;
+        };
+        shared_tables.entry(d.table_name).or_default().push(DropEntry {
+            item,
+            numerator: d.numerator,
+            denominator: d.denominator,
+        });
+    }
+
+    let _ = DROPS.set(tables);
+    let _ = SHARED_DROPS.set(shared_tables);
+    Ok(())
+}
+
+fn drop_item(obj_id: Option<u32>, table: Option<String>, min: u32, max: u32, noted: bool) -> Option<DropItem> {
+    match (obj_id, table) {
+        (Some(obj_id), _) => Some(DropItem::Obj {
+            obj_id: obj_id as u16,
+            min,
+            max,
+            noted,
+        }),
+        (None, Some(name)) => Some(DropItem::Table(name)),
+        (None, None) => None,
+    }
+}
+
 impl From<FaceDirection> for Direction {
     fn from(fd: FaceDirection) -> Self {
         match fd {
@@ -105,3 +173,15 @@ pub fn get_npc_combat(id: u32) -> Option<&'static NpcCombat> {
 pub fn get_npc_spawns() -> &'static [NpcSpawnDef] {
     SPAWNS.get().map(|v| v.as_slice()).unwrap_or(&[])
 }
+
+pub fn get_npc_drops(id: u32) -> Option<&'static DropTable> {
+    DROPS.get().and_then(|m| m.get(&id))
+}
+
+pub fn get_shared_drops(name: &str) -> &'static [DropEntry] {
+    SHARED_DROPS
+        .get()
+        .and_then(|m| m.get(name))
+        .map(|v| v.as_slice())
+        .unwrap_or(&[])
+}
@@ -158,3 +158,12 @@ async fn load_obj_types(ctx: &ProviderContext) -> anyhow::Result<()> {
 pub fn get_obj_type(id: u32) -> Option<&'static ObjType> {
     INSTANCE.get().and_then(|l| l.get(id))
 }
+
+pub fn get_obj_value(id: u32) -> i32 {
+    let Some(t) = get_obj_type(id) else { return 0 };
+    t.noted_template
+        .and(t.noted_id)
+        .and_then(get_obj_type)
+        .unwrap_or(t)
+        .value
+}
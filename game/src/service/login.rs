@@ -1,11 +1,23 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use net::{LoginOutcome, LoginRequest, LoginService, LoginSuccess, SessionError};
-use persistence::{AccountRepository, PlayerData, PlayerRepository};
+use persistence::{
+    Account, AccountRepository, ClanRepository, PlayerData, PlayerRepository, Punishment, PunishmentKind,
+    PunishmentRepository, Rights, SocialRepository, hash_password, is_valid_password,
+};
 use shaku::{Component, Interface};
+use util::{is_valid_username, normalize_name};
 
-use crate::{config::GameConfig, world::World};
+use crate::{
+    config::GameConfig,
+    content,
+    player::broadcast_offline,
+    service::{LoginQueue, SlotReservation},
+    world::{ClanChannel, SessionEvent, World},
+};
+
+const MAX_PLAYER_INDEX: usize = 2047;
 
 pub trait GameLoginService: LoginService + Interface {}
 
@@ -18,11 +30,26 @@ pub struct WorldLoginService {
     #[shaku(inject)]
     players: Arc<dyn PlayerRepository>,
 
+    #[shaku(inject)]
+    social: Arc<dyn SocialRepository>,
+
+    #[shaku(inject)]
+    clans: Arc<dyn ClanRepository>,
+
+    #[shaku(inject)]
+    punishments: Arc<dyn PunishmentRepository>,
+
     #[shaku(default)]
     config: GameConfig,
 
     #[shaku(default)]
     world: Arc<World>,
+
+    #[shaku(default)]
+    checksums: Vec<u32>,
+
+    #[shaku(default)]
+    queue: LoginQueue,
 }
 
 impl GameLoginService for WorldLoginService {}
@@ -33,7 +60,7 @@ impl WorldLoginService {
         session_key: i64,
         server_key: i64,
         version: u32,
-        _crc: &[u32; 31],
+        crc: &[u32; 31],
     ) -> Option<LoginOutcome> {
         if server_key != session_key {
             return Some(LoginOutcome::BadSessionId);
@@ -43,19 +70,132 @@ impl WorldLoginService {
             return Some(LoginOutcome::GameUpdated);
         }
 
+        if let Some((index, client_crc)) = self.stale_index(crc) {
+            let expected = self.checksums.get(index).copied().unwrap_or(0);
+            tracing::debug!(index, client_crc, expected, "Cache CRC Mismatch");
+            return Some(LoginOutcome::GameUpdated);
+        }
+
         None
     }
 
+    fn stale_index(&self, crc: &[u32; 31]) -> Option<(usize, u32)> {
+        crc.iter()
+            .copied()
+            .enumerate()
+            .filter(|&(index, _)| !self.config.crc_allow_list.contains(&(index as u8)))
+            .find(|&(index, client_crc)| client_crc != self.checksums.get(index).copied().unwrap_or(0))
+    }
+
+    fn reserve_slot(&self, rights: Rights) -> Option<SlotReservation<'_>> {
+        let limit = self.config.max_players.min(MAX_PLAYER_INDEX);
+        let limit = match rights {
+            Rights::Moderator | Rights::Admin => limit,
+            Rights::Standard => limit.saturating_sub(self.config.reserved_slots),
+        };
+
+        self.queue.try_reserve(limit, || self.world.players.keys().len())
+    }
+
+    async fn wait_for_slot(&self, rights: Rights) -> Option<SlotReservation<'_>> {
+        if rights != Rights::Standard {
+            return self.reserve_slot(rights);
+        }
+
+        if self.queue.is_empty()
+            && let Some(slot) = self.reserve_slot(rights)
+        {
+            return Some(slot);
+        }
+
+        if !self.config.login_queue {
+            return None;
+        }
+
+        let timeout = Duration::from_secs(self.config.login_queue_timeout_secs);
+        self.queue.wait(timeout, || self.reserve_slot(rights)).await
+    }
+
+    /// Rejects banned accounts and addresses, otherwise returning any active mute:
+    /// `Some(None)` when it is permanent.
+    async fn check_punishments(&self, account_id: i64, ip: &str) -> Result<Option<Option<Duration>>, LoginOutcome> {
+        let punishments = self
+            .punishments
+            .find_active(account_id, ip)
+            .await
+            .map_err(|_| LoginOutcome::LoginServerOffline)?;
+
+        if punishments.iter().any(|p| p.kind == PunishmentKind::IpBan) {
+            return Err(LoginOutcome::AddressBlocked);
+        }
+        if punishments.iter().any(|p| p.kind == PunishmentKind::Ban) {
+            return Err(LoginOutcome::AccountDisabled);
+        }
+
+        let mutes: Vec<Option<Duration>> = punishments
+            .iter()
+            .filter(|p| p.kind == PunishmentKind::Mute)
+            .map(Punishment::remaining)
+            .collect();
+        match mutes.contains(&None) {
+            true => Ok(Some(None)),
+            false => Ok(mutes.into_iter().flatten().max().map(Some)),
+        }
+    }
+
+    /// Creates an account on first login when `auto_register` is enabled, or
+    /// `None` if the username or password would not be allowed.
+    async fn register(&self, username: &str, password: &str) -> anyhow::Result<Option<Account>> {
+        if !is_valid_username(username) || !is_valid_password(password) {
+            return Ok(None);
+        }
+
+        let password = password.to_string();
+        let hash = tokio::task::spawn_blocking(move || hash_password(&password))
+            .await?
+            .map_err(|e| anyhow::anyhow!("failed to hash password: {e}"))?;
+
+        let username = normalize_name(username);
+        let account = match self.accounts.create(&username, &hash, Rights::Standard).await {
+            Ok(account) => account,
+            // A concurrent first login with the same name won the unique constraint;
+            // fall back to its account so the caller verifies the password against it.
+            Err(e) => match self.accounts.find_by_username(&username).await? {
+                Some(account) => return Ok(Some(account)),
+                None => {
+                    tracing::warn!(error = %e, username, "Failed to Register Account");
+                    return Err(e.into());
+                }
+            },
+        };
+
+        tracing::info!(username = account.username, "Account registered");
+        Ok(Some(account))
+    }
+
     async fn load_or_create_player(&self, account_id: i64) -> Result<PlayerData, SessionError> {
-        match self.players.find_by_account_id(account_id).await {
-            Ok(Some(data)) => Ok(data),
+        let mut data = match self.players.find_by_account_id(account_id).await {
+            Ok(Some(data)) => data,
             Ok(None) => self
                 .players
                 .create_default(account_id)
                 .await
-                .map_err(|e| SessionError::Internal(e.to_string())),
-            Err(e) => Err(SessionError::Internal(e.to_string())),
-        }
+                .map_err(|e| SessionError::Internal(e.to_string()))?,
+            Err(e) => return Err(SessionError::Internal(e.to_string())),
+        };
+
+        data.friends = self
+            .social
+            .find_friends(data.player_id)
+            .await
+            .map_err(|e| SessionError::Internal(e.to_string()))?;
+        data.ignores = self
+            .social
+            .find_ignores(data.player_id)
+            .await
+            .map_err(|e| SessionError::Internal(e.to_string()))?;
+
+        Ok(data)
     }
 }
 
@@ -66,6 +206,7 @@ impl LoginService for WorldLoginService {
         &self,
         req: LoginRequest,
         session_key: i64,
+        ip: IpAddr,
     ) -> Result<LoginOutcome, SessionError> {
         if let Some(outcome) =
             self.validate_session(session_key, req.server_key, req.version, &req.crc)
@@ -75,19 +216,59 @@ impl LoginService for WorldLoginService {
 
         let account = match self.accounts.find_by_username(&req.username).await {
             Ok(Some(account)) => account,
-            _ => return Ok(LoginOutcome::InvalidCredentials),
+            Ok(None) if self.config.auto_register => match self.register(&req.username, &req.password).await {
+                Ok(Some(account)) => account,
+                Ok(None) => return Ok(LoginOutcome::InvalidCredentials),
+                Err(_) => return Ok(LoginOutcome::LoginServerOffline),
+            },
+            Ok(None) => return Ok(LoginOutcome::InvalidCredentials),
+            Err(_) => return Ok(LoginOutcome::LoginServerOffline),
         };
 
         if !account.verify_password(&req.password) { return Ok(LoginOutcome::InvalidCredentials); }
         if account.disabled { return Ok(LoginOutcome::AccountDisabled); }
+
+        let ip = ip.to_string();
+        let mute = match self.check_punishments(account.id, &ip).await {
+            Ok(mute) => mute,
+            Err(outcome) => return Ok(outcome),
+        };
+
+        if req.reconnecting
+            && let Some((player_index, inbox_tx, outbound_rx)) = self.world.reattach_player(account.id)
+        {
+            self.world.player_mut(player_index).on_reconnect().await;
+            self.world.sessions.push(SessionEvent::Reconnect { index: player_index, account_id: account.id });
+
+            let success = LoginSuccess {
+                rights: account.rights.into(),
+                player_index,
+                members: true,
+                inbox_tx,
+                outbound_rx,
+            };
+
+            return Ok(LoginOutcome::Success(success));
+        }
+
         if self.world.is_online(account.id) { return Ok(LoginOutcome::AlreadyOnline); }
+        let Some(slot) = self.wait_for_slot(account.rights).await else { return Ok(LoginOutcome::WorldFull) };
 
-        let _ = self.accounts.update_last_login(account.id).await;
+        let _ = self.accounts.update_last_login(account.id, &ip).await;
         let player_data = self.load_or_create_player(account.id).await?;
+        if self.world.players.vacant_index() > MAX_PLAYER_INDEX { return Ok(LoginOutcome::WorldFull); }
         let (player_index, inbox_tx, outbound_rx) =
             self.world.register_player(&account, &player_data, req.display_mode);
+        drop(slot);
 
-        self.world.player_mut(player_index).on_login().await;
+        {
+            let mut player = self.world.player_mut(player_index);
+            if let Some(duration) = mute {
+                player.mute_mut().set(duration);
+            }
+            player.on_login().await;
+        }
+        self.world.sessions.push(SessionEvent::Login { index: player_index, account_id: account.id });
 
         let success = LoginSuccess {
             rights: account.rights.into(),
@@ -101,10 +282,51 @@ impl LoginService for WorldLoginService {
     }
 
     async fn logout(&self, player_index: usize) {
-        if let Some(data) = self.world.unregister_player(player_index)
-            && let Err(e) = self.players.save(&data).await
-        {
+        if self.world.is_attached(player_index) {
+            return;
+        }
+
+        if let Some(signal) = self.world.detach_player(player_index) {
+            let grace = Duration::from_secs(self.config.reconnect_grace_secs);
+            let _ = tokio::time::timeout(grace, signal.notified()).await;
+
+            if !self.world.expire_detached(player_index, &signal) {
+                return;
+            }
+        }
+
+        let mut owner = None;
+        if self.world.players.contains(player_index) {
+            let (name, channel) = {
+                let player = self.world.player(player_index);
+                (
+                    normalize_name(&player.username),
+                    player.clan().channel().map(str::to_string),
+                )
+            };
+            broadcast_offline(&self.world, player_index, &name).await;
+            if let Some(channel) = channel {
+                content::clan_logout(&self.world, player_index, &channel).await;
+            }
+            owner = Some(name);
+        }
+
+        let Some(data) = self.world.unregister_player(player_index) else {
+            return;
+        };
+
+        self.queue.slot_freed();
+
+        if let Err(e) = self.players.save(&data).await {
             tracing::warn!(error = %e, "Failed to Save Player Data");
         }
+        if let Err(e) = self.social.save(&data).await {
+            tracing::warn!(error = %e, "Failed to Save Social Lists");
+        }
+        if let Some(channel) = owner.and_then(|o| self.world.clans.with(&o, ClanChannel::to_data))
+            && let Err(e) = self.clans.save(&channel).await
+        {
+            tracing::warn!(error = %e, "Failed to Save Clan Channel");
+        }
     }
 }
@@ -1,8 +1,14 @@
 mod login;
 mod manager;
+mod moderation;
 mod monitor;
+mod queue;
 mod world;
+mod worldlist;
 
 pub use login::{GameLoginService, WorldLoginService, WorldLoginServiceParameters};
 pub use manager::ServiceManager;
+pub use moderation::{ModerationService, PunishOutcome, PunishRequest, WorldModerationService};
+pub use queue::{LoginQueue, SlotReservation};
 pub use world::WorldService;
+pub use worldlist::WorldPopulationService;
@@ -0,0 +1,82 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use persistence::{AccountRepository, NewPunishment, Punishment, PunishmentKind, PunishmentRepository, Rights};
+use shaku::{Component, Interface};
+
+pub struct PunishRequest {
+    pub target: String,
+    pub kind: PunishmentKind,
+    /// `None` for a permanent punishment.
+    pub duration: Option<Duration>,
+    pub reason: String,
+    pub staff_id: i64,
+    pub staff_rights: Rights,
+}
+
+pub enum PunishOutcome {
+    Applied(Punishment),
+    UnknownAccount,
+    /// The target's rights are equal to or above the issuing staff member's.
+    Protected,
+    /// An IP ban was requested for an account that has never logged in.
+    UnknownAddress,
+}
+
+#[async_trait]
+pub trait ModerationService: Interface {
+    async fn punish(&self, request: PunishRequest) -> anyhow::Result<PunishOutcome>;
+    /// Lifts every active punishment of the given kinds, or `None` if the account doesn't exist.
+    async fn revoke(&self, target: &str, kinds: &[PunishmentKind]) -> anyhow::Result<Option<u64>>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ModerationService)]
+pub struct WorldModerationService {
+    #[shaku(inject)]
+    accounts: Arc<dyn AccountRepository>,
+
+    #[shaku(inject)]
+    punishments: Arc<dyn PunishmentRepository>,
+}
+
+#[async_trait]
+impl ModerationService for WorldModerationService {
+    async fn punish(&self, request: PunishRequest) -> anyhow::Result<PunishOutcome> {
+        let Some(account) = self.accounts.find_by_username(&request.target).await? else {
+            return Ok(PunishOutcome::UnknownAccount);
+        };
+        if account.rights >= request.staff_rights {
+            return Ok(PunishOutcome::Protected);
+        }
+
+        let ip = match request.kind {
+            PunishmentKind::IpBan => match account.last_ip {
+                Some(ip) => Some(ip),
+                None => return Ok(PunishOutcome::UnknownAddress),
+            },
+            _ => None,
+        };
+
+        let punishment = self
+            .punishments
+            .create(NewPunishment {
+                account_id: account.id,
+                kind: request.kind,
+                ip,
+                reason: request.reason,
+                staff_id: Some(request.staff_id),
+                duration: request.duration,
+            })
+            .await?;
+
+        Ok(PunishOutcome::Applied(punishment))
+    }
+
+    async fn revoke(&self, target: &str, kinds: &[PunishmentKind]) -> anyhow::Result<Option<u64>> {
+        let Some(account) = self.accounts.find_by_username(target).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.punishments.revoke(account.id, kinds).await?))
+    }
+}
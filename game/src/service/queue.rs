@@ -0,0 +1,78 @@
+use std::{
+    collections::VecDeque,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+#[derive(Default)]
+pub struct LoginQueue {
+    next_ticket: AtomicU64,
+    tickets: Mutex<VecDeque<u64>>,
+    reserved: Mutex<usize>,
+    notify: Notify,
+}
+
+/// A player slot held between admission and `register_player`, so concurrent
+/// logins cannot all pass the capacity check. Released when dropped.
+pub struct SlotReservation<'a> {
+    queue: &'a LoginQueue,
+}
+
+impl Drop for SlotReservation<'_> {
+    fn drop(&mut self) {
+        *self.queue.reserved.lock() -= 1;
+        self.queue.notify.notify_waiters();
+    }
+}
+
+impl LoginQueue {
+    pub fn is_empty(&self) -> bool {
+        self.tickets.lock().is_empty()
+    }
+
+    /// Claims a slot when the `online` count plus outstanding reservations is under
+    /// `limit`. `online` is read under the reservation lock so it cannot go stale.
+    pub fn try_reserve(&self, limit: usize, online: impl FnOnce() -> usize) -> Option<SlotReservation<'_>> {
+        let mut reserved = self.reserved.lock();
+        if online() + *reserved >= limit {
+            return None;
+        }
+        *reserved += 1;
+        Some(SlotReservation { queue: self })
+    }
+
+    pub async fn wait<T>(&self, timeout: Duration, mut admit: impl FnMut() -> Option<T>) -> Option<T> {
+        let ticket = self.next_ticket.fetch_add(1, Ordering::Relaxed);
+        self.tickets.lock().push_back(ticket);
+
+        let admitted = tokio::time::timeout(timeout, async {
+            loop {
+                let notified = self.notify.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                if self.tickets.lock().front() == Some(&ticket)
+                    && let Some(admitted) = admit()
+                {
+                    return admitted;
+                }
+
+                notified.await;
+            }
+        })
+        .await
+        .ok();
+
+        self.tickets.lock().retain(|&t| t != ticket);
+        self.notify.notify_waiters();
+
+        admitted
+    }
+
+    pub fn slot_freed(&self) {
+        self.notify.notify_waiters();
+    }
+}
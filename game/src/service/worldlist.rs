@@ -0,0 +1,65 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use net::WorldListService;
+use parking_lot::RwLock;
+use persistence::PopulationRepository;
+use tokio_util::sync::CancellationToken;
+
+use crate::world::World;
+
+const PUBLISH_INTERVAL: Duration = Duration::from_secs(30);
+/// How recently another world must have published to still be listed as online.
+const LIVE_WINDOW: Duration = Duration::from_secs(90);
+
+pub struct WorldPopulationService {
+    world_id: u16,
+    world: Arc<World>,
+    repository: Arc<dyn PopulationRepository>,
+    others: RwLock<HashMap<u16, usize>>,
+}
+
+impl WorldPopulationService {
+    pub fn new(world_id: u16, world: Arc<World>, repository: Arc<dyn PopulationRepository>) -> Self {
+        Self {
+            world_id,
+            world,
+            repository,
+            others: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Publishes this world's count and picks up every other world's, so each
+    /// server's world list shows live counts for all of them.
+    pub async fn run_until(&self, cancel: CancellationToken) {
+        let mut interval = tokio::time::interval(PUBLISH_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = interval.tick() => self.refresh().await,
+            }
+        }
+
+        let _ = self.repository.publish(self.world_id, 0).await;
+    }
+
+    async fn refresh(&self) {
+        let count = self.world.players.keys().len();
+        if let Err(e) = self.repository.publish(self.world_id, count).await {
+            tracing::warn!(error = %e, "Failed to Publish World Population");
+        }
+
+        match self.repository.find_live(LIVE_WINDOW).await {
+            Ok(counts) => *self.others.write() = counts.into_iter().collect(),
+            Err(e) => tracing::warn!(error = %e, "Failed to Load World Populations"),
+        }
+    }
+}
+
+impl WorldListService for WorldPopulationService {
+    fn player_count(&self, world_id: u16) -> Option<usize> {
+        match world_id == self.world_id {
+            true => Some(self.world.players.keys().len()),
+            false => self.others.read().get(&world_id).copied(),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parking_lot::Mutex;
+use persistence::{ClanChannelData, Rights};
+use util::normalize_name;
+
+pub const MAX_MEMBERS: usize = 100;
+const KICK_DURATION: Duration = Duration::from_secs(60 * 60);
+
+#[repr(i8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, TryFromPrimitive, IntoPrimitive)]
+pub enum ClanRank {
+    Anyone = -1,
+    Friend = 0,
+    Recruit = 1,
+    Corporal = 2,
+    Sergeant = 3,
+    Lieutenant = 4,
+    Captain = 5,
+    General = 6,
+    Owner = 7,
+    Staff = 127,
+}
+
+impl ClanRank {
+    pub fn label(self) -> &'static str {
+        match self {
+            ClanRank::Anyone => "Anyone",
+            ClanRank::Friend => "Any friends",
+            ClanRank::Recruit => "Recruit+",
+            ClanRank::Corporal => "Corporal+",
+            ClanRank::Sergeant => "Sergeant+",
+            ClanRank::Lieutenant => "Lieutenant+",
+            ClanRank::Captain => "Captain+",
+            ClanRank::General => "General+",
+            ClanRank::Owner | ClanRank::Staff => "Only me",
+        }
+    }
+}
+
+pub struct ClanMember {
+    pub index: usize,
+    pub name: String,
+    pub rights: Rights,
+}
+
+pub struct ClanChannel {
+    pub owner_id: i64,
+    pub owner: String,
+    pub name: String,
+    pub join_rank: ClanRank,
+    pub talk_rank: ClanRank,
+    pub kick_rank: ClanRank,
+    pub ranks: HashMap<String, ClanRank>,
+    pub bans: HashSet<String>,
+    pub members: Vec<ClanMember>,
+    kicked: HashMap<String, Instant>,
+}
+
+impl ClanChannel {
+    pub fn new(owner_id: i64, owner: String, name: String) -> Self {
+        Self {
+            owner_id,
+            owner,
+            name,
+            join_rank: ClanRank::Anyone,
+            talk_rank: ClanRank::Anyone,
+            kick_rank: ClanRank::Owner,
+            ranks: HashMap::new(),
+            bans: HashSet::new(),
+            members: Vec::new(),
+            kicked: HashMap::new(),
+        }
+    }
+
+    fn from_data(data: ClanChannelData) -> Self {
+        let rank = |r: i8, default| ClanRank::try_from(r).unwrap_or(default);
+        Self {
+            join_rank: rank(data.join_rank, ClanRank::Anyone),
+            talk_rank: rank(data.talk_rank, ClanRank::Anyone),
+            kick_rank: rank(data.kick_rank, ClanRank::Owner),
+            ranks: data
+                .ranks
+                .into_iter()
+                .filter_map(|(name, r)| Some((name, ClanRank::try_from(r).ok()?)))
+                .collect(),
+            bans: data.bans.into_iter().collect(),
+            ..Self::new(data.owner_id, normalize_name(&data.owner), data.name)
+        }
+    }
+
+    pub fn to_data(&self) -> ClanChannelData {
+        ClanChannelData {
+            owner_id: self.owner_id,
+            owner: self.owner.clone(),
+            name: self.name.clone(),
+            join_rank: self.join_rank.into(),
+            talk_rank: self.talk_rank.into(),
+            kick_rank: self.kick_rank.into(),
+            ranks: self.ranks.iter().map(|(name, r)| (name.clone(), (*r).into())).collect(),
+            bans: self.bans.iter().cloned().collect(),
+        }
+    }
+
+    pub fn rank_of(&self, name: &str, rights: Rights) -> ClanRank {
+        if rights >= Rights::Admin {
+            ClanRank::Staff
+        } else if name == self.owner {
+            ClanRank::Owner
+        } else {
+            self.ranks.get(name).copied().unwrap_or(ClanRank::Anyone)
+        }
+    }
+
+    pub fn member(&self, index: usize) -> Option<&ClanMember> {
+        self.members.iter().find(|m| m.index == index)
+    }
+
+    pub fn member_indices(&self) -> Vec<usize> {
+        self.members.iter().map(|m| m.index).collect()
+    }
+
+    pub fn remove_member(&mut self, index: usize) -> Option<ClanMember> {
+        let pos = self.members.iter().position(|m| m.index == index)?;
+        Some(self.members.remove(pos))
+    }
+
+    /// Whether `name` is banned, either permanently or by a kick that has not yet expired.
+    pub fn is_banned(&mut self, name: &str) -> bool {
+        self.kicked.retain(|_, until| *until > Instant::now());
+        self.bans.contains(name) || self.kicked.contains_key(name)
+    }
+
+    pub fn kick(&mut self, name: &str) {
+        self.kicked.insert(name.to_string(), Instant::now() + KICK_DURATION);
+    }
+}
+
+/// Every clan channel on the world, keyed by the normalized name of its owner.
+#[derive(Default)]
+pub struct ClanStore {
+    channels: Mutex<HashMap<String, ClanChannel>>,
+}
+
+impl ClanStore {
+    pub fn load(&self, channels: Vec<ClanChannelData>) {
+        let mut inner = self.channels.lock();
+        for data in channels {
+            let channel = ClanChannel::from_data(data);
+            inner.insert(channel.owner.clone(), channel);
+        }
+    }
+
+    pub fn contains(&self, owner: &str) -> bool {
+        self.channels.lock().contains_key(owner)
+    }
+
+    pub fn insert(&self, channel: ClanChannel) {
+        self.channels.lock().insert(channel.owner.clone(), channel);
+    }
+
+    pub fn with<R>(&self, owner: &str, f: impl FnOnce(&ClanChannel) -> R) -> Option<R> {
+        self.channels.lock().get(owner).map(f)
+    }
+
+    pub fn with_mut<R>(&self, owner: &str, f: impl FnOnce(&mut ClanChannel) -> R) -> Option<R> {
+        self.channels.lock().get_mut(owner).map(f)
+    }
+}
@@ -1,8 +1,10 @@
+mod clan;
 mod collision;
 mod loc;
 mod objstack;
 mod pathfinding;
 mod position;
+mod session;
 mod slab;
 mod tick;
 
@@ -11,9 +13,10 @@ use std::{
     sync::{Arc, OnceLock, Weak},
 };
 
+pub use clan::{ClanChannel, ClanMember, ClanRank, ClanStore, MAX_MEMBERS as CLAN_MAX_MEMBERS};
 pub use collision::{CollisionMap, LocParams};
 pub use loc::{LocStore, TempLoc, TempLocSnapshot};
-use net::{Frame, IncomingMessage, Logout, OutboxExt};
+use net::{Frame, IncomingMessage};
 pub use objstack::ObjStackStore;
 use parking_lot::Mutex;
 pub use pathfinding::{
@@ -22,13 +25,17 @@ pub use pathfinding::{
 };
 use persistence::{Account, PlayerData};
 pub use position::{Direction, Position, RegionId, Teleport, running_direction};
+pub use session::{SessionEvent, SessionQueue};
 pub use slab::WorldSlab;
-use tokio::sync::mpsc;
+use tokio::sync::{Notify, mpsc};
+use util::normalize_name;
 
 use crate::{
-    content::PendingHit,
+    config::SafeZone,
+    content::{CombatTarget, PendingHit},
     npc::{Npc, NpcActionState, NpcCombat, NpcSnapshot},
-    player::{ActionState, Player, PlayerSnapshot},
+    player::{ActionState, Clientbound, Player, PlayerSnapshot},
+    service::ModerationService,
     world::slab::{SlabReadGuard, SlabWriteGuard},
 };
 
@@ -48,10 +55,17 @@ pub struct World {
     pub npcs: WorldSlab<Npc>,
     pub obj_stacks: ObjStackStore,
     pub locs: LocStore,
+    pub clans: ClanStore,
+    pub sessions: SessionQueue,
     pub action_states: Mutex<HashMap<usize, ActionState>>,
     pub npc_action_states: Mutex<HashMap<usize, NpcActionState>>,
     pub pending_hits: Mutex<Vec<PendingHit>>,
     npc_respawns: Mutex<Vec<NpcRespawn>>,
+    detached: Mutex<HashMap<usize, Arc<Notify>>>,
+    player_names: Mutex<HashMap<String, usize>>,
+    safe_zones: OnceLock<Vec<SafeZone>>,
+    world_id: OnceLock<u16>,
+    moderation: OnceLock<Arc<dyn ModerationService>>,
 }
 
 impl Default for World {
@@ -62,10 +76,17 @@ impl Default for World {
             npcs: WorldSlab::new(),
             obj_stacks: ObjStackStore::default(),
             locs: LocStore::default(),
+            clans: ClanStore::default(),
+            sessions: SessionQueue::default(),
             action_states: Mutex::new(HashMap::new()),
             npc_action_states: Mutex::new(HashMap::new()),
             pending_hits: Mutex::new(Vec::new()),
             npc_respawns: Mutex::new(Vec::new()),
+            detached: Mutex::new(HashMap::new()),
+            player_names: Mutex::new(HashMap::new()),
+            safe_zones: OnceLock::new(),
+            world_id: OnceLock::new(),
+            moderation: OnceLock::new(),
         }
     }
 }
@@ -89,6 +110,34 @@ impl World {
         }
     }
 
+    pub fn set_safe_zones(&self, zones: Vec<SafeZone>) {
+        let _ = self.safe_zones.set(zones);
+    }
+
+    pub fn set_world_id(&self, world_id: u16) {
+        let _ = self.world_id.set(world_id);
+    }
+
+    pub fn world_id(&self) -> u16 {
+        self.world_id.get().copied().unwrap_or(1)
+    }
+
+    pub fn set_moderation(&self, moderation: Arc<dyn ModerationService>) {
+        let _ = self.moderation.set(moderation);
+    }
+
+    pub fn moderation(&self) -> Option<Arc<dyn ModerationService>> {
+        self.moderation.get().cloned()
+    }
+
+    pub fn in_safe_zone(&self, pos: Position) -> bool {
+        self.safe_zones.get().is_some_and(|zones| {
+            zones
+                .iter()
+                .any(|z| (z.min_x..=z.max_x).contains(&pos.x) && (z.min_y..=z.max_y).contains(&pos.y))
+        })
+    }
+
     pub fn register_player(
         &self,
         account: &Account,
@@ -98,9 +147,12 @@ impl World {
         let (inbox_tx, inbox_rx) = mpsc::channel::<IncomingMessage>(128);
         let (outbound_tx, outbound_rx) = mpsc::channel::<Frame>(128);
         let snapshots = self.player_snapshots();
-        let index = self.players.vacant_index();
-        let player = Player::new(index, account, player_data, inbox_rx, outbound_tx, &snapshots);
-        self.players.insert(player);
+        let index = self
+            .players
+            .insert_with(|index| Player::new(index, account, player_data, inbox_rx, outbound_tx, &snapshots));
+        self.player_names
+            .lock()
+            .insert(normalize_name(&account.username), index);
 
         let mut guard = self.players.get_mut(index);
         guard.set_world(&self.arc());
@@ -115,17 +167,97 @@ impl World {
         }
 
         self.action_states.lock().remove(&player_index);
-        let player = self.players.remove(player_index);
+        let mut player = self.players.remove(player_index);
+        self.player_names.lock().remove(&normalize_name(&player.username));
+        for obj in player.trade_mut().end() {
+            player.inv_mut().insert(obj.id, obj.amount);
+        }
 
         tracing::info!(index = player.index, username = player.username, "Player Logged Out");
 
         Some(player.to_player_data())
     }
 
+    pub fn detach_player(&self, player_index: usize) -> Option<Arc<Notify>> {
+        if !self.players.contains(player_index) {
+            return None;
+        }
+
+        let snapshots = self.player_snapshots();
+        let mut player = self.players.get_mut(player_index);
+        if player.logging_out {
+            return None;
+        }
+
+        let (_, inbox) = mpsc::channel::<IncomingMessage>(1);
+        let (outbox, _) = mpsc::channel::<Frame>(1);
+        player.attach(inbox, outbox, &snapshots);
+
+        let signal = Arc::new(Notify::new());
+        self.detached.lock().insert(player_index, signal.clone());
+
+        tracing::info!(index = player.index, username = player.username, "Player Disconnected");
+
+        Some(signal)
+    }
+
+    /// Hands a reconnecting client its player back, whether the old connection has
+    /// already been detached or its end has not been noticed yet.
+    pub fn reattach_player(
+        &self,
+        account_id: i64,
+    ) -> Option<(usize, mpsc::Sender<IncomingMessage>, mpsc::Receiver<Frame>)> {
+        let detached: Vec<usize> = self.detached.lock().keys().copied().collect();
+        let (index, signal) = match detached
+            .into_iter()
+            .find(|&index| self.players.contains(index) && self.players.get(index).account_id == account_id)
+        {
+            Some(index) => (index, Some(self.detached.lock().remove(&index)?)),
+            None => (self.find_attached(account_id)?, None),
+        };
+
+        let (inbox_tx, inbox_rx) = mpsc::channel::<IncomingMessage>(128);
+        let (outbound_tx, outbound_rx) = mpsc::channel::<Frame>(128);
+        let snapshots = self.player_snapshots();
+        self.players.get_mut(index).attach(inbox_rx, outbound_tx, &snapshots);
+        match signal {
+            Some(signal) => signal.notify_one(),
+            None => tracing::info!(index, "Player Session Replaced"),
+        }
+
+        Some((index, inbox_tx, outbound_rx))
+    }
+
+    fn find_attached(&self, account_id: i64) -> Option<usize> {
+        self.players.keys().into_iter().find(|&index| {
+            self.players.contains(index) && {
+                let player = self.players.get(index);
+                player.account_id == account_id && !player.logging_out
+            }
+        })
+    }
+
+    /// Whether the player is still driven by a live connection. A session that was
+    /// replaced by a reconnect ends after the new one attached, and must not log out.
+    pub fn is_attached(&self, player_index: usize) -> bool {
+        self.players.contains(player_index) && !self.players.get(player_index).outbox.is_closed()
+    }
+
+    pub fn expire_detached(&self, player_index: usize, signal: &Arc<Notify>) -> bool {
+        let mut detached = self.detached.lock();
+        match detached.get(&player_index) {
+            Some(current) if Arc::ptr_eq(current, signal) => detached.remove(&player_index).is_some(),
+            _ => false,
+        }
+    }
+
     pub async fn signal_logout_all(&self) {
-        let outboxes: Vec<_> = self.players.map(|p| p.outbox.clone());
-        for mut outbox in outboxes {
-            outbox.write(Logout).await;
+        for index in self.players.keys() {
+            self.players.get_mut(index).logout().await;
+        }
+
+        for signal in self.detached.lock().values() {
+            signal.notify_one();
         }
     }
 
@@ -160,6 +292,13 @@ impl World {
         for idx in dead {
             self.npc_action_states.lock().remove(&idx);
             let npc = self.npcs.remove(idx);
+            let owner = match npc.damage_ledger.top_damager() {
+                Some(CombatTarget::Player(index)) if self.players.contains(index) => Some(index),
+                _ => None,
+            };
+            for (obj_id, amount) in crate::npc::roll_drops(npc.npc_id) {
+                self.obj_stacks.add(obj_id, amount, npc.position, owner);
+            }
             respawns.push(NpcRespawn {
                 npc_id: npc.npc_id,
                 position: npc.spawn_position,
@@ -227,6 +366,10 @@ impl World {
         self.npcs.get_mut(index)
     }
 
+    pub fn find_player(&self, name: &str) -> Option<usize> {
+        self.player_names.lock().get(&normalize_name(name)).copied()
+    }
+
     pub fn is_online(&self, account_id: i64) -> bool {
         self.players.any(|p| p.account_id == account_id)
     }
@@ -36,6 +36,8 @@ struct StoreInner {
 
 const PRIVATE_TICKS: u16 = 100;
 const PUBLIC_TICKS: u16 = 200;
+const DEATH_PVM_PRIVATE_TICKS: u16 = 300;
+const DEATH_PVP_PRIVATE_TICKS: u16 = 100;
 
 impl ObjStackStore {
     pub fn add(&self, obj_id: u16, amount: u32, position: Position, owner: Option<usize>) -> u32 {
@@ -69,6 +71,11 @@ impl ObjStackStore {
         id
     }
 
+    pub fn add_death_drop(&self, obj_id: u16, amount: u32, position: Position, owner: usize, pvp: bool) -> u32 {
+        let private_ticks = if pvp { DEATH_PVP_PRIVATE_TICKS } else { DEATH_PVM_PRIVATE_TICKS };
+        self.add_with_state(obj_id, amount, position, Some(owner), private_ticks, PUBLIC_TICKS)
+    }
+
     pub fn remove(&self, id: u32) -> Option<ObjStack> {
         self.inner.lock().stacks.remove(&id)
     }
@@ -0,0 +1,82 @@
+use std::{future::Future, pin::Pin};
+
+use parking_lot::Mutex;
+
+use crate::{
+    content,
+    player::{Player, broadcast_status},
+    world::World,
+};
+
+type DeferredFn = Box<dyn for<'a> FnOnce(&'a mut Player) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send>;
+
+struct Deferred {
+    index: usize,
+    account_id: i64,
+    f: DeferredFn,
+}
+
+/// Login work that touches other players, applied on the tick.
+#[derive(Debug, Clone, Copy)]
+pub enum SessionEvent {
+    Login { index: usize, account_id: i64 },
+    Reconnect { index: usize, account_id: i64 },
+}
+
+#[derive(Default)]
+pub struct SessionQueue {
+    events: Mutex<Vec<SessionEvent>>,
+    deferred: Mutex<Vec<Deferred>>,
+}
+
+impl SessionQueue {
+    pub fn push(&self, event: SessionEvent) {
+        self.events.lock().push(event);
+    }
+
+    fn drain(&self) -> Vec<SessionEvent> {
+        std::mem::take(&mut *self.events.lock())
+    }
+}
+
+impl World {
+    pub fn defer<F>(&self, index: usize, account_id: i64, f: F)
+    where
+        F: for<'a> FnOnce(&'a mut Player) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send + 'static,
+    {
+        let f = Box::new(f);
+        self.sessions.deferred.lock().push(Deferred { index, account_id, f });
+    }
+}
+
+impl World {
+    pub(super) async fn process_sessions(&self) {
+        for event in self.sessions.drain() {
+            let (SessionEvent::Login { index, account_id } | SessionEvent::Reconnect { index, account_id }) = event;
+            if !self.players.contains(index) {
+                continue;
+            }
+
+            let mut player = self.player_mut(index);
+            if player.account_id != account_id {
+                continue;
+            }
+            player.social_mut().send_friends().await;
+            if let SessionEvent::Login { .. } = event {
+                broadcast_status(self, &player, true).await;
+                content::rejoin_clan(&mut player).await;
+            }
+        }
+
+        let deferred = std::mem::take(&mut *self.sessions.deferred.lock());
+        for Deferred { index, account_id, f } in deferred {
+            if !self.players.contains(index) {
+                continue;
+            }
+            let mut player = self.player_mut(index);
+            if player.account_id == account_id {
+                f(&mut player).await;
+            }
+        }
+    }
+}
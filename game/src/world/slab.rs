@@ -38,6 +38,16 @@ impl<T> WorldSlab<T> {
         self.inner.write().insert(RwLock::new(value)) + 1
     }
 
+    /// Builds the value from the index it will occupy, holding the slab lock so a
+    /// concurrent insert cannot take that index first.
+    pub fn insert_with(&self, f: impl FnOnce(usize) -> T) -> usize {
+        let mut slab = self.inner.write();
+        let entry = slab.vacant_entry();
+        let index = entry.key() + 1;
+        entry.insert(RwLock::new(f(index)));
+        index
+    }
+
     pub fn remove(&self, index: usize) -> T {
         self.inner.write().remove(index - 1).into_inner()
     }
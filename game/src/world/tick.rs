@@ -4,7 +4,7 @@ use net::{InboxExt, IncomingMessage};
 use parking_lot::Mutex;
 
 use crate::{
-    content::process_pending_hits,
+    content::{hunt, process_pending_hits},
     handler::handle_incoming_message,
     npc::{Npc, NpcSnapshot},
     player::{Player, PlayerSnapshot, resolve_interaction},
@@ -34,6 +34,7 @@ trait WorldTickPhase {
     async fn execute(&self, world: &World);
 }
 
+struct Sessions;
 struct ProcessMessages;
 struct Move;
 struct Tick;
@@ -90,11 +91,13 @@ impl TickPhase<Npc> for Tick {
     type Context = ();
     fn context(&self, _: &World) -> Self::Context {}
 
-    async fn execute(&self, _world: &World, npc: &mut Npc, _: &()) {
+    async fn execute(&self, world: &World, npc: &mut Npc, _: &()) {
         if npc.tick_death() {
             return;
         }
+        npc.damage_ledger.tick();
         crate::npc::resolve_action(npc);
+        hunt(npc, world);
         npc.wander();
         npc.process_movement();
     }
@@ -143,6 +146,12 @@ impl TickPhase<Npc> for Reset {
     }
 }
 
+impl WorldTickPhase for Sessions {
+    async fn execute(&self, world: &World) {
+        world.process_sessions().await;
+    }
+}
+
 impl WorldTickPhase for WorldTick {
     async fn execute(&self, world: &World) {
         process_pending_hits(world);
@@ -157,6 +166,7 @@ impl WorldTickPhase for WorldTick {
 impl World {
     pub async fn tick(&self) {
         tick!(self,
+            world:  Sessions,
             player: ProcessMessages,
             npc:    Tick,
             player: Move,
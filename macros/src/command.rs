@@ -8,17 +8,53 @@ use syn::{
 
 struct CommandAttr {
     name: LitStr,
+    rights: syn::Ident,
+    aliases: Vec<LitStr>,
+    description: Option<LitStr>,
 }
 
 impl Parse for CommandAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let ident: syn::Ident = input.parse()?;
-        if ident != "name" {
-            return Err(syn::Error::new(ident.span(), "expected `name`"));
+        let mut name = None;
+        let mut rights = None;
+        let mut aliases = Vec::new();
+        let mut description = None;
+
+        while !input.is_empty() {
+            let ident: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match ident.to_string().as_str() {
+                "name" => name = Some(input.parse()?),
+                "rights" => rights = Some(input.parse()?),
+                "aliases" => {
+                    let content;
+                    syn::bracketed!(content in input);
+                    aliases = content
+                        .parse_terminated(|p| p.parse::<LitStr>(), Token![,])?
+                        .into_iter()
+                        .collect();
+                }
+                "description" => description = Some(input.parse()?),
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "expected `name`, `rights`, `aliases` or `description`",
+                    ));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
         }
-        input.parse::<Token![=]>()?;
-        let name: LitStr = input.parse()?;
-        Ok(CommandAttr { name })
+
+        let name: LitStr = name.ok_or_else(|| input.error("missing `name`"))?;
+        let rights = rights.unwrap_or_else(|| syn::Ident::new("Admin", name.span()));
+        Ok(CommandAttr {
+            name,
+            rights,
+            aliases,
+            description,
+        })
     }
 }
 
@@ -55,6 +91,12 @@ pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
     let func = parse_macro_input!(item as ItemFn);
     let func_name = &func.sig.ident;
     let cmd_name = &attr.name;
+    let rights = &attr.rights;
+    let aliases = &attr.aliases;
+    let description = attr
+        .description
+        .clone()
+        .unwrap_or_else(|| LitStr::new("", cmd_name.span()));
     let wrapper_name = format_ident!("__{}_command_wrapper", func_name);
 
     let all_params: Vec<_> = func
@@ -89,7 +131,7 @@ pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
         .collect();
 
     let usage_lit = LitStr::new(
-        &format!("Usage: ::{} {}", cmd_name.value(), usage_parts.join(" ")),
+        format!("::{} {}", cmd_name.value(), usage_parts.join(" ")).trim_end(),
         cmd_name.span(),
     );
     let mut parse_stmts = Vec::new();
@@ -104,15 +146,21 @@ pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
             });
         } else if let Some(inner_ty) = extract_option_inner(ty) {
             parse_stmts.push(quote! {
-                let #name: Option<#inner_ty> = __args.get(#i)
-                    .and_then(|s| s.parse().ok());
+                let #name: Option<#inner_ty> = match __args.get(#i).map(|s| s.parse()) {
+                    None => None,
+                    Some(Ok(v)) => Some(v),
+                    Some(Err(_)) => {
+                        crate::send_message!(player, "Usage: {}", #usage_lit);
+                        return;
+                    }
+                };
             });
         } else {
             parse_stmts.push(quote! {
                 let #name: #ty = match __args.get(#i).and_then(|s| s.parse().ok()) {
                     Some(v) => v,
                     None => {
-                        crate::send_message!(player, "{}", #usage_lit);
+                        crate::send_message!(player, "Usage: {}", #usage_lit);
                         return;
                     }
                 };
@@ -151,6 +199,10 @@ pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
         inventory::submit! {
             CommandEntry {
                 name: #cmd_name,
+                aliases: &[#(#aliases),*],
+                rights: persistence::Rights::#rights,
+                description: #description,
+                usage: #usage_lit,
                 handle: #wrapper_name,
             }
         }
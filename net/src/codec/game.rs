@@ -5,6 +5,7 @@ use tokio_util::{
 
 use crate::{Frame, Prefix, SessionError, crypto::StreamCipher};
 
+// -1: byte-prefixed, -2: short-prefixed, -3: not sent by the 592 client
 const SIZES: [i16; 256] = {
     let mut a = [-3; 256];
 
@@ -148,6 +149,7 @@ where
 
                     let prefix = match size_marker {
                         -1 => Prefix::Byte,
+                        -2 => Prefix::Short,
                         _ => Prefix::Fixed,
                     };
 
@@ -163,16 +165,13 @@ where
                 }
 
                 State::Size { opcode, prefix } => {
-                    if src.is_empty() {
-                        return Ok(None);
-                    }
-
-                    let size = src.get_u8();
-                    self.state = State::Payload {
-                        opcode,
-                        prefix,
-                        size: size as usize,
+                    let size = match prefix {
+                        Prefix::Short if src.len() >= 2 => src.get_u16() as usize,
+                        Prefix::Byte if !src.is_empty() => src.get_u8() as usize,
+                        _ => return Ok(None),
                     };
+
+                    self.state = State::Payload { opcode, prefix, size };
                 }
 
                 State::Payload { opcode, prefix, size } => {
@@ -22,8 +22,10 @@ impl Decoder for HandshakeCodec {
         }
 
         let opcode = src[0];
-        let handshake_type =
-            HandshakeType::try_from(opcode).map_err(|_| SessionError::InvalidHandshakeOpcode(opcode))?;
+        let Ok(handshake_type) = HandshakeType::try_from(opcode) else {
+            src.advance(OPCODE_LEN);
+            return Ok(Some(HandshakeInbound::Unknown { opcode }));
+        };
 
         let needed = OPCODE_LEN + handshake_type.len();
         if src.len() < needed {
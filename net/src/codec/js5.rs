@@ -17,35 +17,43 @@ impl Decoder for Js5Codec {
     type Error = SessionError;
 
     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        loop {
-            if src.len() < 4 {
-                return Ok(None);
-            }
+        if src.len() < 4 {
+            return Ok(None);
+        }
 
-            let opcode = src.get_u8();
-            let request_opcode =
-                RequestOpcode::try_from(opcode).map_err(|_| SessionError::InvalidRequestOpcode(opcode))?;
+        let opcode = src.get_u8();
+        let request_opcode = RequestOpcode::try_from(opcode).map_err(|_| SessionError::InvalidRequestOpcode(opcode))?;
 
-            match request_opcode {
-                RequestOpcode::Normal | RequestOpcode::Urgent => {
-                    let urgent = request_opcode == RequestOpcode::Urgent;
-                    let index_id = src.get_u8();
-                    let archive_id = src.get_u16() as u32;
-                    let request = FileRequest::new(urgent, IndexId::new(index_id), ArchiveId::new(archive_id));
+        let item = match request_opcode {
+            RequestOpcode::Normal | RequestOpcode::Urgent => {
+                let urgent = request_opcode == RequestOpcode::Urgent;
+                let index_id = src.get_u8();
+                let archive_id = src.get_u16() as u32;
+                let request = FileRequest::new(urgent, IndexId::new(index_id), ArchiveId::new(archive_id));
 
-                    return Ok(Some(Js5Inbound::FileRequest(request)));
-                }
+                Js5Inbound::FileRequest(request)
+            }
 
-                RequestOpcode::EncryptionKey => {
-                    let key = src.get_u8();
-                    src.advance(2);
+            RequestOpcode::EncryptionKey => {
+                let key = src.get_u8();
+                src.advance(2);
 
-                    return Ok(Some(Js5Inbound::EncryptionKey(key)));
-                }
+                Js5Inbound::EncryptionKey(key)
+            }
+
+            control => {
+                src.advance(3);
 
-                _ => src.advance(3),
+                match control {
+                    RequestOpcode::LoggedIn => Js5Inbound::LoggedIn,
+                    RequestOpcode::LoggedOut => Js5Inbound::LoggedOut,
+                    RequestOpcode::Connected => Js5Inbound::Connected,
+                    _ => Js5Inbound::Disconnected,
+                }
             }
-        }
+        };
+
+        Ok(Some(item))
     }
 }
 
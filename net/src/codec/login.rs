@@ -1,9 +1,11 @@
+use std::sync::Arc;
+
 use rand::RngExt;
 use tokio_util::{
     bytes::{Buf, BufMut, BytesMut},
     codec::{Decoder, Encoder},
 };
-use util::{BufExt, EXPONENT, MODULUS, decode_base37, rsa_decrypt};
+use util::{BufExt, RsaKeyPair, decode_base37};
 
 use crate::{
     error::SessionError,
@@ -13,23 +15,27 @@ use crate::{
 #[derive(Debug)]
 pub struct LoginCodec {
     hash: u8,
+    rsa_key: Arc<RsaKeyPair>,
     state: LoginState,
     size: usize,
+    reconnecting: bool,
 }
 
 impl LoginCodec {
-    fn new(hash: u8) -> Self {
+    fn new(hash: u8, rsa_key: Arc<RsaKeyPair>) -> Self {
         Self {
             hash,
+            rsa_key,
             state: LoginState::Header,
             size: 0,
+            reconnecting: false,
         }
     }
 
-    pub fn with_random_key(hash: u8) -> (Self, i64) {
+    pub fn with_random_key(hash: u8, rsa_key: Arc<RsaKeyPair>) -> (Self, i64) {
         let mut rng = rand::rng();
         let session_key: i64 = rng.random();
-        (Self::new(hash), session_key)
+        (Self::new(hash, rsa_key), session_key)
     }
 }
 
@@ -50,6 +56,8 @@ impl Decoder for LoginCodec {
             if r#type != 16 && r#type != 18 {
                 return Err(SessionError::InvalidLoginType(r#type));
             }
+
+            self.reconnecting = r#type == 18;
         }
 
         if self.state == LoginState::Payload {
@@ -81,7 +89,7 @@ impl Decoder for LoginCodec {
 
             let encrypted_size = src.get_u8() as usize;
             let encrypted_block = src.split_to(encrypted_size);
-            let plain_bytes = rsa_decrypt(&encrypted_block, MODULUS, EXPONENT)?;
+            let plain_bytes = self.rsa_key.decrypt(&encrypted_block);
             let mut secure_buf = BytesMut::from(&plain_bytes[..]);
             let encrypted_type = secure_buf.get_u8();
             if encrypted_type != 10 {
@@ -104,6 +112,7 @@ impl Decoder for LoginCodec {
                 crc,
                 client_key,
                 server_key,
+                reconnecting: self.reconnecting,
                 username,
                 password,
             });
@@ -1,12 +1,32 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, path::PathBuf};
 
 use serde::Deserialize;
 
+use crate::message::{CountryFlag, WorldFlag};
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct TcpConfig {
     pub bind_addr: SocketAddr,
     pub max_connections: usize,
+    pub max_connections_per_ip: usize,
+    pub max_login_failures: u32,
+    pub login_backoff_secs: u64,
+    pub max_packets_per_tick: usize,
+    pub max_malformed_packets: usize,
+    pub js5_prefetch_workers: usize,
+    pub js5_prefetch_bytes_per_sec: u64,
+    pub rsa_key: PathBuf,
+    pub worlds: Vec<WorldConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WorldConfig {
+    pub id: u16,
+    pub host: String,
+    pub activity: String,
+    pub country: CountryFlag,
+    pub flags: WorldFlag,
 }
 
 impl Default for TcpConfig {
@@ -14,6 +34,21 @@ impl Default for TcpConfig {
         Self {
             bind_addr: "127.0.0.1:43594".parse().unwrap(),
             max_connections: 100,
+            max_connections_per_ip: 5,
+            max_login_failures: 5,
+            login_backoff_secs: 30,
+            max_packets_per_tick: 50,
+            max_malformed_packets: 10,
+            js5_prefetch_workers: 4,
+            js5_prefetch_bytes_per_sec: 512 * 1024,
+            rsa_key: PathBuf::from("rsa.toml"),
+            worlds: vec![WorldConfig {
+                id: 1,
+                host: "127.0.0.1".to_string(),
+                activity: "Members".to_string(),
+                country: CountryFlag::Usa,
+                flags: WorldFlag::MEMBERS | WorldFlag::LOOTSHARE,
+            }],
         }
     }
 }
@@ -1,5 +1,5 @@
-mod noop;
+mod isaac;
 mod stream;
 
-pub(crate) use noop::NoopCipher;
+pub(crate) use isaac::IsaacCipher;
 pub(crate) use stream::StreamCipher;
@@ -29,6 +29,12 @@ pub enum SessionError {
     #[error("Message {0} has illegal size")]
     InvalidMessageSize(u8),
 
+    #[error("Inbound packet limit exceeded: {0} packets in one tick")]
+    PacketFlood(usize),
+
+    #[error("Too many malformed packets: {0}")]
+    MalformedPackets(usize),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -44,4 +50,11 @@ impl SessionError {
             _ => false,
         }
     }
+
+    pub fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            SessionError::VersionMismatch | SessionError::InvalidHandshakeOpcode(_)
+        )
+    }
 }
@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use futures_util::{SinkExt, StreamExt};
-use tokio::{net::TcpStream, sync::mpsc};
+use tokio::{net::TcpStream, sync::mpsc, time::Instant};
 use tokio_util::codec::Framed;
 
-use crate::{Frame, IncomingMessage, SessionError, codec::GameCodec, crypto::StreamCipher};
+use crate::{Frame, IncomingMessage, SessionError, codec::GameCodec, crypto::StreamCipher, session::SessionContext};
+
+const TICK: Duration = Duration::from_millis(600);
 
 pub struct GameHandler;
 
@@ -13,12 +17,16 @@ impl GameHandler {
         out_cipher: COut,
         inbox_tx: mpsc::Sender<IncomingMessage>,
         mut outbox_rx: mpsc::Receiver<Frame>,
+        context: &SessionContext,
     ) -> Result<(), SessionError>
     where
         CIn: StreamCipher + 'static,
         COut: StreamCipher + 'static,
     {
         let mut framed = Framed::new(stream, GameCodec::new(in_cipher, out_cipher));
+        let mut window_start = Instant::now();
+        let mut window_packets = 0;
+        let mut malformed = 0;
 
         loop {
             tokio::select! {
@@ -29,7 +37,28 @@ impl GameHandler {
                         None => break,
                     };
 
-                    if let Some(decoded) = crate::inbound::decode(msg)
+                    if window_start.elapsed() >= TICK {
+                        window_start = Instant::now();
+                        window_packets = 0;
+                    }
+
+                    window_packets += 1;
+                    if window_packets > context.max_packets_per_tick {
+                        return Err(SessionError::PacketFlood(window_packets));
+                    }
+
+                    let decoded = match crate::inbound::decode(msg) {
+                        Ok(decoded) => decoded,
+                        Err(_) => {
+                            malformed += 1;
+                            if malformed >= context.max_malformed_packets {
+                                return Err(SessionError::MalformedPackets(malformed));
+                            }
+                            None
+                        }
+                    };
+
+                    if let Some(decoded) = decoded
                         && inbox_tx.send(decoded).await.is_err()
                     {
                         break;
@@ -1,3 +1,5 @@
+use std::net::SocketAddr;
+
 use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpStream;
 use tokio_util::codec::Framed;
@@ -12,7 +14,11 @@ use crate::{
 pub struct HandshakeHandler;
 
 impl HandshakeHandler {
-    pub async fn run(stream: TcpStream) -> anyhow::Result<(TcpStream, SessionPhase), SessionError> {
+    pub async fn run(
+        stream: TcpStream,
+        peer_addr: SocketAddr,
+        client_version: u32,
+    ) -> anyhow::Result<(TcpStream, SessionPhase), SessionError> {
         let codec = HandshakeCodec;
         let mut framed = Framed::new(stream, codec);
 
@@ -24,23 +30,32 @@ impl HandshakeHandler {
         };
 
         let phase = match frame? {
-            HandshakeInbound::Js5 { client_version } => {
-                let response = match client_version == 592 {
-                    true => HandshakeResponse::Success,
-                    false => HandshakeResponse::OutOfDate,
-                };
-
-                framed.send(HandshakeOutbound::Response(response)).await?;
-
-                if response != HandshakeResponse::Success {
+            HandshakeInbound::Js5 {
+                client_version: revision,
+            } => {
+                if revision != client_version {
+                    tracing::warn!(addr = %peer_addr, revision, expected = client_version, "Handshake Rejected");
+                    framed
+                        .send(HandshakeOutbound::Response(HandshakeResponse::OutOfDate))
+                        .await?;
                     return Err(SessionError::VersionMismatch);
                 }
 
+                framed
+                    .send(HandshakeOutbound::Response(HandshakeResponse::Success))
+                    .await?;
                 SessionPhase::Js5
             }
 
             HandshakeInbound::WorldList { full_update } => SessionPhase::WorldList { full_update },
             HandshakeInbound::Login { hash } => SessionPhase::Login { hash },
+            HandshakeInbound::Unknown { opcode } => {
+                tracing::warn!(addr = %peer_addr, opcode, "Unknown Handshake Rejected");
+                framed
+                    .send(HandshakeOutbound::Response(HandshakeResponse::Rejected))
+                    .await?;
+                return Err(SessionError::InvalidHandshakeOpcode(opcode));
+            }
         };
 
         let parts = framed.into_parts();
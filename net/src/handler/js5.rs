@@ -1,4 +1,4 @@
-use std::{collections::BinaryHeap, future::poll_fn, sync::Arc, task::Poll};
+use std::{collections::BinaryHeap, future::poll_fn, sync::Arc, task::Poll, time::Duration};
 
 use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpStream;
@@ -8,77 +8,125 @@ use crate::{
     codec::{Js5Codec, XorCodec},
     error::SessionError,
     message::{Js5Inbound, Js5Outbound, PriorityRequest},
-    service::CacheService,
+    service::{BandwidthLimiter, CacheService},
+    session::SessionContext,
 };
 
+type Js5Framed = Framed<TcpStream, XorCodec<Js5Codec>>;
+
+struct Js5State {
+    queue: BinaryHeap<PriorityRequest>,
+    sequence: u64,
+    logged_in: bool,
+    prefetch: BandwidthLimiter,
+}
+
+/// What [`Js5Handler::recv`] got: a message, nothing before the wait ran out, or
+/// the end of the stream.
+enum Received {
+    Message(Result<Js5Inbound, SessionError>),
+    Idle,
+    Closed,
+}
+
 pub struct Js5Handler;
 
 impl Js5Handler {
-    pub async fn run(stream: TcpStream, service: Arc<CacheService>) -> anyhow::Result<(), SessionError> {
+    pub async fn run(stream: TcpStream, context: Arc<SessionContext>) -> anyhow::Result<(), SessionError> {
         let codec = Js5Codec;
         let xor_codec = XorCodec::new(codec);
         let mut framed = Framed::new(stream, xor_codec);
-        let mut queue: BinaryHeap<PriorityRequest> = BinaryHeap::new();
-        let mut sequence: u64 = 0;
+        let mut state = Js5State {
+            queue: BinaryHeap::new(),
+            sequence: 0,
+            logged_in: false,
+            prefetch: BandwidthLimiter::new(context.js5_prefetch_bytes_per_sec),
+        };
 
         loop {
-            match Self::recv(&mut framed, queue.is_empty()).await {
-                Some(Ok(msg)) => Self::read(msg, &mut framed, &mut queue, &mut sequence),
-                Some(Err(e)) => return Err(e),
-                None if queue.is_empty() => return Ok(()),
-                None => {}
+            let wait = Self::next_delay(&mut state);
+            match Self::recv(&mut framed, wait).await {
+                Received::Message(Ok(Js5Inbound::Disconnected)) | Received::Closed => return Ok(()),
+                Received::Message(Ok(msg)) => Self::read(msg, &mut framed, &mut state),
+                Received::Message(Err(e)) => return Err(e),
+                Received::Idle => {}
             }
 
-            Self::process(&mut framed, &mut queue, &service).await?;
+            Self::process(&mut framed, &mut state, &context.cache_service).await?;
         }
     }
 
-    async fn recv(
-        framed: &mut Framed<TcpStream, XorCodec<Js5Codec>>,
-        blocking: bool,
-    ) -> Option<Result<Js5Inbound, SessionError>> {
-        if blocking {
-            return framed.next().await;
+    fn next_delay(state: &mut Js5State) -> Option<Duration> {
+        let next = state.queue.peek()?;
+        match next.request.urgent || !state.logged_in {
+            true => Some(Duration::ZERO),
+            false => Some(state.prefetch.delay()),
+        }
+    }
+
+    async fn recv(framed: &mut Js5Framed, wait: Option<Duration>) -> Received {
+        let received = |msg: Option<Result<Js5Inbound, SessionError>>| match msg {
+            Some(msg) => Received::Message(msg),
+            None => Received::Closed,
+        };
+
+        let Some(wait) = wait else {
+            return received(framed.next().await);
+        };
+
+        if !wait.is_zero() {
+            return match tokio::time::timeout(wait, framed.next()).await {
+                Ok(msg) => received(msg),
+                Err(_) => Received::Idle,
+            };
         }
 
         poll_fn(|cx| {
             Poll::Ready(match framed.poll_next_unpin(cx) {
-                Poll::Ready(msg) => msg,
-                Poll::Pending => None,
+                Poll::Ready(msg) => received(msg),
+                Poll::Pending => Received::Idle,
             })
         })
         .await
     }
 
-    fn read(
-        msg: Js5Inbound,
-        framed: &mut Framed<TcpStream, XorCodec<Js5Codec>>,
-        queue: &mut BinaryHeap<PriorityRequest>,
-        sequence: &mut u64,
-    ) {
+    fn read(msg: Js5Inbound, framed: &mut Js5Framed, state: &mut Js5State) {
         match msg {
             Js5Inbound::FileRequest(request) => {
-                queue.push(PriorityRequest {
+                state.queue.push(PriorityRequest {
                     request,
-                    sequence: *sequence,
+                    sequence: state.sequence,
                 });
-                *sequence += 1;
+                state.sequence += 1;
             }
 
             Js5Inbound::EncryptionKey(key) => framed.codec_mut().set_xor_key(key),
+            Js5Inbound::LoggedIn => state.logged_in = true,
+            Js5Inbound::LoggedOut => state.logged_in = false,
+            Js5Inbound::Connected | Js5Inbound::Disconnected => {}
         }
     }
 
     async fn process(
-        framed: &mut Framed<TcpStream, XorCodec<Js5Codec>>,
-        queue: &mut BinaryHeap<PriorityRequest>,
+        framed: &mut Js5Framed,
+        state: &mut Js5State,
         service: &Arc<CacheService>,
     ) -> anyhow::Result<(), SessionError> {
-        let Some(priority) = queue.pop() else {
+        if Self::next_delay(state).is_some_and(|delay| !delay.is_zero()) {
+            return Ok(());
+        }
+
+        let Some(priority) = state.queue.pop() else {
             return Ok(());
         };
 
         let request = priority.request;
+        let urgent = request.urgent;
+        let slot = match urgent {
+            true => None,
+            false => service.acquire_prefetch_slot().await,
+        };
+
         let service = Arc::clone(service);
         let file_result = tokio::task::spawn_blocking(move || {
             service.get_file(&request).ok().map(|data| Js5Outbound {
@@ -91,8 +139,13 @@ impl Js5Handler {
         .await
         .ok()
         .flatten();
+        drop(slot);
 
         if let Some(outbound) = file_result {
+            if !urgent && state.logged_in {
+                state.prefetch.consume(outbound.data.len());
+            }
+
             framed.send(outbound).await?;
         }
 
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpStream;
@@ -7,18 +7,23 @@ use tokio_util::codec::Framed;
 use crate::{
     LoginOutcome,
     codec::LoginCodec,
-    crypto::NoopCipher,
+    crypto::IsaacCipher,
     error::SessionError,
     handler::GameHandler,
     message::{LoginInbound, LoginOutbound, LoginResponse},
-    service::LoginService,
+    session::SessionContext,
 };
 
 pub struct LoginHandler;
 
 impl LoginHandler {
-    pub async fn run(stream: TcpStream, hash: u8, service: Arc<dyn LoginService>) -> anyhow::Result<(), SessionError> {
-        let (codec, session_key) = LoginCodec::with_random_key(hash);
+    pub async fn run(
+        stream: TcpStream,
+        hash: u8,
+        peer_addr: SocketAddr,
+        context: Arc<SessionContext>,
+    ) -> anyhow::Result<(), SessionError> {
+        let (codec, session_key) = LoginCodec::with_random_key(hash, Arc::clone(&context.rsa_key));
         let mut framed = Framed::new(stream, codec);
         framed.send(LoginOutbound::SessionKey(session_key)).await?;
 
@@ -30,7 +35,23 @@ impl LoginHandler {
         };
 
         let LoginInbound::Request(request) = request?;
-        let outcome = service.authenticate(request, session_key).await?;
+        let (client_key, server_key, revision) = (request.client_key, request.server_key, request.version);
+        let ip = peer_addr.ip();
+        let outcome = match context.login_throttle.is_blocked(ip) {
+            true => LoginOutcome::TooManyAttempts,
+            false => context.login_service.authenticate(request, session_key, ip).await?,
+        };
+
+        match outcome {
+            LoginOutcome::InvalidCredentials => context.login_throttle.record_failure(ip),
+            LoginOutcome::Success(_) => context.login_throttle.clear(ip),
+            LoginOutcome::GameUpdated if revision != context.client_version => {
+                tracing::warn!(addr = %peer_addr, revision, expected = context.client_version, "Login Rejected")
+            }
+            LoginOutcome::GameUpdated => tracing::warn!(addr = %peer_addr, "Login Rejected: Cache CRC Mismatch"),
+            _ => {}
+        }
+
         let response = LoginResponse::from_outcome(&outcome);
         framed.send(LoginOutbound::Response(response)).await?;
 
@@ -39,12 +60,15 @@ impl LoginHandler {
                 let player_index = s.player_index;
                 let parts = framed.into_parts();
                 let stream = parts.io;
-                let in_cipher = NoopCipher;
-                let out_cipher = NoopCipher;
+                let in_cipher = IsaacCipher::from_keys(client_key, server_key, 0);
+                let out_cipher = IsaacCipher::from_keys(client_key, server_key, 50);
 
-                let result = GameHandler::run(stream, in_cipher, out_cipher, s.inbox_tx, s.outbound_rx).await;
+                let result = GameHandler::run(stream, in_cipher, out_cipher, s.inbox_tx, s.outbound_rx, &context).await;
 
-                service.logout(player_index).await;
+                let login_service = Arc::clone(&context.login_service);
+                context
+                    .tracker
+                    .spawn(async move { login_service.logout(player_index).await });
 
                 result
             }
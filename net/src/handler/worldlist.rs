@@ -1,38 +1,23 @@
+use std::sync::Arc;
+
 use futures_util::SinkExt;
 use tokio::net::TcpStream;
 use tokio_util::codec::Framed;
 
-use crate::{
-    codec::WorldListCodec,
-    error::SessionError,
-    message::{Country, CountryFlag, World, WorldFlag, WorldListOutbound},
-};
+use crate::{codec::WorldListCodec, error::SessionError, service::WorldDirectory};
 
 pub struct WorldListHandler;
 
 impl WorldListHandler {
-    pub async fn run(stream: TcpStream, full_update: bool) -> anyhow::Result<(), SessionError> {
+    pub async fn run(
+        stream: TcpStream,
+        full_update: bool,
+        directory: Arc<WorldDirectory>,
+    ) -> anyhow::Result<(), SessionError> {
         let codec = WorldListCodec;
         let mut framed = Framed::new(stream, codec);
 
-        let outbound = WorldListOutbound {
-            full_update,
-            countries: vec![Country {
-                flag: CountryFlag::Usa,
-                name: "USA".to_string(),
-            }],
-            worlds: vec![World {
-                id: 1,
-                location: 0,
-                flags: WorldFlag::MEMBERS | WorldFlag::LOOTSHARE,
-                activity: "Members".to_string(),
-                hostname: "127.0.0.1".to_string(),
-                player_count: 0,
-            }],
-            session_id: 0xDEADBEEF,
-        };
-
-        framed.send(outbound).await?;
+        framed.send(directory.snapshot(full_update)).await?;
 
         Ok(())
     }
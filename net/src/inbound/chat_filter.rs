@@ -0,0 +1,22 @@
+use macros::message_decoder;
+use tokio_util::bytes::{Buf, Bytes};
+
+use super::{DecodeResult, InboundDecoder};
+
+#[derive(Debug)]
+pub struct SetChatFilterSettings {
+    pub public: u8,
+    pub private: u8,
+    pub trade: u8,
+}
+
+const OPCODE: u8 = 36;
+
+#[message_decoder]
+fn decode(mut payload: Bytes) -> DecodeResult {
+    let public = payload.try_get_u8()?;
+    let private = payload.try_get_u8()?;
+    let trade = payload.try_get_u8()?;
+
+    Ok(Box::new(SetChatFilterSettings { public, private, trade }))
+}
@@ -0,0 +1,56 @@
+use macros::message_decoder;
+use tokio_util::bytes::{Buf, Bytes};
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder};
+
+#[derive(Debug)]
+pub struct ClanJoinChat {
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct ClanKickUser {
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct ClanSetRank {
+    pub name: String,
+    pub rank: i8,
+}
+
+const _: () = {
+    const OPCODE: u8 = 24;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(ClanJoinChat {
+            name: payload.get_string(),
+        }))
+    }
+};
+
+const _: () = {
+    const OPCODE: u8 = 32;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(ClanKickUser {
+            name: payload.get_string(),
+        }))
+    }
+};
+
+const _: () = {
+    const OPCODE: u8 = 44;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let rank = payload.try_get_i8()?;
+        Ok(Box::new(ClanSetRank {
+            name: payload.get_string(),
+            rank,
+        }))
+    }
+};
@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 pub struct ClientCheat {
     pub command: String,
@@ -12,8 +12,8 @@ pub struct ClientCheat {
 const OPCODE: u8 = 78;
 
 #[message_decoder]
-fn decode(mut payload: Bytes) -> IncomingMessage {
-    let client_sent = payload.get_u8() == 1;
+fn decode(mut payload: Bytes) -> DecodeResult {
+    let client_sent = payload.try_get_u8()? == 1;
     let command = payload.get_string();
-    Box::new(ClientCheat { command, client_sent })
+    Ok(Box::new(ClientCheat { command, client_sent }))
 }
@@ -1,7 +1,7 @@
 use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 pub struct ExamLoc {
     pub id: u16,
@@ -11,7 +11,9 @@ const _: () = {
     const OPCODE: u8 = 73;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        Box::new(ExamLoc { id: payload.get_u16() })
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(ExamLoc {
+            id: payload.try_get_u16()?,
+        }))
     }
 };
@@ -0,0 +1,37 @@
+use macros::message_decoder;
+use tokio_util::bytes::Bytes;
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder};
+
+#[derive(Debug)]
+pub struct FriendlistAdd {
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct FriendlistDel {
+    pub name: String,
+}
+
+const _: () = {
+    const OPCODE: u8 = 53;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(FriendlistAdd {
+            name: payload.get_string(),
+        }))
+    }
+};
+
+const _: () = {
+    const OPCODE: u8 = 4;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(FriendlistDel {
+            name: payload.get_string(),
+        }))
+    }
+};
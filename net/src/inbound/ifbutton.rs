@@ -1,7 +1,7 @@
 use macros::message_decoder;
-use tokio_util::bytes::{Buf, Bytes};
+use tokio_util::bytes::{Buf, Bytes, TryGetError};
 
-use super::{InboundDecoder, IncomingMessage, Op};
+use super::{DecodeResult, InboundDecoder, Op};
 
 #[derive(Debug)]
 pub struct IfButton {
@@ -12,25 +12,25 @@ pub struct IfButton {
     pub slot2: u16,
 }
 
-fn decode_ifbutton(payload: &mut Bytes, op: Op) -> IfButton {
-    let hash = payload.get_u32();
-    let slot1 = payload.get_u16_le();
-    let slot2 = payload.get_u16_le();
-    IfButton {
+fn decode_ifbutton(payload: &mut Bytes, op: Op) -> Result<IfButton, TryGetError> {
+    let hash = payload.try_get_u32()?;
+    let slot1 = payload.try_get_u16_le()?;
+    let slot2 = payload.try_get_u16_le()?;
+    Ok(IfButton {
         op,
         interface: (hash >> 16) as u16,
         component: (hash & 0xffff) as u16,
         slot1,
         slot2,
-    }
+    })
 }
 
 const _: () = {
     const OPCODE: u8 = 6;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op1))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op1)?))
     }
 };
 
@@ -38,8 +38,8 @@ const _: () = {
     const OPCODE: u8 = 38;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op2))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op2)?))
     }
 };
 
@@ -47,8 +47,8 @@ const _: () = {
     const OPCODE: u8 = 62;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op3))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op3)?))
     }
 };
 
@@ -56,8 +56,8 @@ const _: () = {
     const OPCODE: u8 = 46;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op4))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op4)?))
     }
 };
 
@@ -65,8 +65,8 @@ const _: () = {
     const OPCODE: u8 = 64;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op5))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op5)?))
     }
 };
 
@@ -74,8 +74,8 @@ const _: () = {
     const OPCODE: u8 = 8;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op6))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op6)?))
     }
 };
 
@@ -83,8 +83,8 @@ const _: () = {
     const OPCODE: u8 = 28;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op7))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op7)?))
     }
 };
 
@@ -92,8 +92,8 @@ const _: () = {
     const OPCODE: u8 = 70;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op8))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op8)?))
     }
 };
 
@@ -101,8 +101,8 @@ const _: () = {
     const OPCODE: u8 = 66;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op9))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op9)?))
     }
 };
 
@@ -110,7 +110,7 @@ const _: () = {
     const OPCODE: u8 = 20;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(decode_ifbutton(&mut p, Op::Op10))
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(decode_ifbutton(&mut p, Op::Op10)?))
     }
 };
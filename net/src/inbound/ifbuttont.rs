@@ -0,0 +1,33 @@
+use macros::message_decoder;
+use tokio_util::bytes::{Buf, Bytes};
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder, SelectedComponent, decode_selected};
+
+#[derive(Debug)]
+pub struct IfButtonT {
+    pub selected: SelectedComponent,
+    pub interface: u16,
+    pub component: u16,
+    pub slot: u16,
+    pub obj: u16,
+}
+
+const _: () = {
+    const OPCODE: u8 = 55;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let hash = payload.try_get_u32_mid_le()?;
+        let slot = payload.try_get_u16_add()?;
+        let obj = payload.try_get_u16_le()?;
+        let selected = decode_selected(&mut payload)?;
+        Ok(Box::new(IfButtonT {
+            selected,
+            interface: (hash >> 16) as u16,
+            component: (hash & 0xffff) as u16,
+            slot,
+            obj,
+        }))
+    }
+};
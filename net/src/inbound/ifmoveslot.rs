@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 #[derive(Debug)]
 pub struct IfMoveSlot {
@@ -18,21 +18,21 @@ const _: () = {
     const OPCODE: u8 = 75;
 
     #[message_decoder]
-    fn decode_if_move_slot(mut payload: Bytes) -> IncomingMessage {
-        let from_hash = payload.get_u32();
-        let from_slot = payload.get_u16_le_add();
-        let to_hash = payload.get_u32_mid_le();
-        let to_slot = payload.get_u16_le_add();
-        let _ = payload.get_u16_le();
-        let _ = payload.get_u16_le();
+    fn decode_if_move_slot(mut payload: Bytes) -> DecodeResult {
+        let from_hash = payload.try_get_u32()?;
+        let from_slot = payload.try_get_u16_le_add()?;
+        let to_hash = payload.try_get_u32_mid_le()?;
+        let to_slot = payload.try_get_u16_le_add()?;
+        let _ = payload.try_get_u16_le()?;
+        let _ = payload.try_get_u16_le()?;
 
-        Box::new(IfMoveSlot {
+        Ok(Box::new(IfMoveSlot {
             from_interface: (from_hash >> 16) as u16,
             from_component: (from_hash & 0xffff) as u16,
             from_slot,
             to_interface: (to_hash >> 16) as u16,
             to_component: (to_hash & 0xffff) as u16,
             to_slot,
-        })
+        }))
     }
 };
@@ -1,7 +1,7 @@
 use macros::message_decoder;
 use tokio_util::bytes::Bytes;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 pub struct IfSubClosed;
 
@@ -9,7 +9,7 @@ const _: () = {
     const OPCODE: u8 = 69;
 
     #[message_decoder]
-    fn decode(_: Bytes) -> IncomingMessage {
-        Box::new(IfSubClosed)
+    fn decode(_: Bytes) -> DecodeResult {
+        Ok(Box::new(IfSubClosed))
     }
 };
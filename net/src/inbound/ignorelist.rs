@@ -0,0 +1,37 @@
+use macros::message_decoder;
+use tokio_util::bytes::Bytes;
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder};
+
+#[derive(Debug)]
+pub struct IgnorelistAdd {
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct IgnorelistDel {
+    pub name: String,
+}
+
+const _: () = {
+    const OPCODE: u8 = 15;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(IgnorelistAdd {
+            name: payload.get_string(),
+        }))
+    }
+};
+
+const _: () = {
+    const OPCODE: u8 = 9;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        Ok(Box::new(IgnorelistDel {
+            name: payload.get_string(),
+        }))
+    }
+};
@@ -0,0 +1,26 @@
+use macros::message_decoder;
+use tokio_util::bytes::Bytes;
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder, MAX_CHAT_LEN};
+
+#[derive(Debug)]
+pub struct MessagePrivate {
+    pub to: String,
+    pub text_len: usize,
+    pub payload: Vec<u8>,
+}
+
+const OPCODE: u8 = 72;
+
+#[message_decoder]
+fn decode(mut payload: Bytes) -> DecodeResult {
+    let to = payload.get_string();
+    let text_len = (payload.try_get_smart()? as usize).min(MAX_CHAT_LEN);
+
+    Ok(Box::new(MessagePrivate {
+        to,
+        text_len,
+        payload: payload.to_vec(),
+    }))
+}
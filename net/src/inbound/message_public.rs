@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder, MAX_CHAT_LEN};
 
 #[derive(Debug)]
 pub struct MessagePublic {
@@ -15,16 +15,16 @@ pub struct MessagePublic {
 const OPCODE: u8 = 79;
 
 #[message_decoder]
-fn decode(mut payload: Bytes) -> IncomingMessage {
-    let _script = payload.get_u8();
-    let color = payload.get_u8();
-    let effect = payload.get_u8();
-    let text_len = payload.get_smart() as usize;
+fn decode(mut payload: Bytes) -> DecodeResult {
+    let _script = payload.try_get_u8()?;
+    let color = payload.try_get_u8()?;
+    let effect = payload.try_get_u8()?;
+    let text_len = (payload.try_get_smart()? as usize).min(MAX_CHAT_LEN);
 
-    Box::new(MessagePublic {
+    Ok(Box::new(MessagePublic {
         color,
         effect,
         text_len,
         payload: payload.to_vec(),
-    })
+    }))
 }
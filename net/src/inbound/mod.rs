@@ -1,9 +1,15 @@
+mod chat_filter;
+mod clan;
 mod client_cheat;
 mod examloc;
+mod friendlist;
 mod ifbutton;
+mod ifbuttont;
 mod ifdialogcontinue;
 mod ifmoveslot;
 mod ifsubclosed;
+mod ignorelist;
+mod message_private;
 mod message_public;
 mod moveclick;
 mod oploc;
@@ -11,16 +17,24 @@ mod opnpc;
 mod opnpct;
 mod opobj;
 mod opplayer;
+mod opplayert;
 mod resumecountdialog;
+mod resumenamedialog;
 
 use std::any::Any;
 
+pub use chat_filter::SetChatFilterSettings;
+pub use clan::{ClanJoinChat, ClanKickUser, ClanSetRank};
 pub use client_cheat::ClientCheat;
 pub use examloc::ExamLoc;
+pub use friendlist::{FriendlistAdd, FriendlistDel};
 pub use ifbutton::IfButton;
+pub use ifbuttont::IfButtonT;
 pub use ifdialogcontinue::IfDialogContinue;
 pub use ifmoveslot::IfMoveSlot;
 pub use ifsubclosed::IfSubClosed;
+pub use ignorelist::{IgnorelistAdd, IgnorelistDel};
+pub use message_private::MessagePrivate;
 pub use message_public::MessagePublic;
 pub use moveclick::MoveClick;
 pub use oploc::OpLoc;
@@ -28,9 +42,12 @@ pub use opnpc::OpNpc;
 pub use opnpct::OpNpcT;
 pub use opobj::OpObj;
 pub use opplayer::OpPlayer;
+pub use opplayert::OpPlayerT;
 pub use resumecountdialog::ResumeCountDialog;
+pub use resumenamedialog::ResumeNameDialog;
 use tokio::sync::mpsc;
-use tokio_util::bytes::Bytes;
+use tokio_util::bytes::{Buf, Bytes, TryGetError};
+use util::BufExt;
 
 use crate::Frame;
 
@@ -49,6 +66,26 @@ pub enum Op {
     OpT,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct SelectedComponent {
+    pub interface: u16,
+    pub component: u16,
+    pub slot: u16,
+    pub obj: u16,
+}
+
+fn decode_selected(payload: &mut Bytes) -> Result<SelectedComponent, TryGetError> {
+    let hash = payload.try_get_u32_le()?;
+    let slot = payload.try_get_u16_le_add()?;
+    let obj = payload.try_get_u16_add()?;
+    Ok(SelectedComponent {
+        interface: (hash >> 16) as u16,
+        component: (hash & 0xffff) as u16,
+        slot,
+        obj,
+    })
+}
+
 pub type IncomingMessage = Box<dyn Any + Send>;
 
 pub type Inbox = mpsc::Receiver<IncomingMessage>;
@@ -67,7 +104,11 @@ impl InboxExt for Inbox {
     }
 }
 
-type DecodeFn = fn(Bytes) -> IncomingMessage;
+const MAX_CHAT_LEN: usize = 80;
+
+pub type DecodeResult = Result<IncomingMessage, TryGetError>;
+
+type DecodeFn = fn(Bytes) -> DecodeResult;
 
 pub struct InboundDecoder {
     pub opcode: u8,
@@ -97,13 +138,20 @@ const IGNORED_OPCODES: [u8; 10] = [
     48, // Window Focus
 ];
 
-pub fn decode(frame: Frame) -> Option<IncomingMessage> {
+pub fn decode(frame: Frame) -> Result<Option<IncomingMessage>, TryGetError> {
     match DECODERS[frame.opcode as usize] {
-        Some(decode) => Some(decode(frame.payload)),
-        None if IGNORED_OPCODES.contains(&frame.opcode) => None,
+        Some(decode) => match decode(frame.payload.clone()) {
+            Ok(msg) => Ok(Some(msg)),
+            Err(e) => {
+                let payload: String = frame.payload.iter().map(|b| format!("{b:02x}")).collect();
+                tracing::warn!(opcode = frame.opcode, payload, error = %e, "Malformed Frame");
+                Err(e)
+            }
+        },
+        None if IGNORED_OPCODES.contains(&frame.opcode) => Ok(None),
         None => {
             tracing::debug!(opcode = frame.opcode, "Unhandled Frame");
-            None
+            Ok(None)
         }
     }
 }
@@ -1,7 +1,7 @@
 use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 pub struct MoveClick {
     pub x: u16,
@@ -13,11 +13,11 @@ const _: () = {
     const OPCODE: u8 = 5;
 
     #[message_decoder]
-    fn decode_gameclick(mut payload: Bytes) -> IncomingMessage {
-        let ctrl_run = payload.get_u8() == 1;
-        let x = payload.get_u16();
-        let y = payload.get_u16();
-        Box::new(MoveClick { x, y, ctrl_run })
+    fn decode_gameclick(mut payload: Bytes) -> DecodeResult {
+        let ctrl_run = payload.try_get_u8()? == 1;
+        let x = payload.try_get_u16()?;
+        let y = payload.try_get_u16()?;
+        Ok(Box::new(MoveClick { x, y, ctrl_run }))
     }
 };
 
@@ -25,10 +25,10 @@ const _: () = {
     const OPCODE: u8 = 59;
 
     #[message_decoder]
-    fn decode_minimapclick(mut payload: Bytes) -> IncomingMessage {
-        let ctrl_run = payload.get_u8() == 1;
-        let x = payload.get_u16();
-        let y = payload.get_u16();
-        Box::new(MoveClick { x, y, ctrl_run })
+    fn decode_minimapclick(mut payload: Bytes) -> DecodeResult {
+        let ctrl_run = payload.try_get_u8()? == 1;
+        let x = payload.try_get_u16()?;
+        let y = payload.try_get_u16()?;
+        Ok(Box::new(MoveClick { x, y, ctrl_run }))
     }
 };
@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 use crate::inbound::Op;
 
 pub struct OpLoc {
@@ -17,18 +17,18 @@ const _: () = {
     const OPCODE: u8 = 77;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let x = payload.get_u16_le_add();
-        let ctrl_run = payload.get_u8() == 1;
-        let id = payload.get_u16();
-        let y = payload.get_u16();
-        Box::new(OpLoc {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let x = payload.try_get_u16_le_add()?;
+        let ctrl_run = payload.try_get_u8()? == 1;
+        let id = payload.try_get_u16()?;
+        let y = payload.try_get_u16()?;
+        Ok(Box::new(OpLoc {
             op: Op::Op1,
             id,
             x,
             y,
             ctrl_run,
-        })
+        }))
     }
 };
 
@@ -36,18 +36,18 @@ const _: () = {
     const OPCODE: u8 = 14;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let x = payload.get_u16_add();
-        let y = payload.get_u16_add();
-        let id = payload.get_u16_le_add();
-        let ctrl_run = payload.get_u8() == 1;
-        Box::new(OpLoc {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let x = payload.try_get_u16_add()?;
+        let y = payload.try_get_u16_add()?;
+        let id = payload.try_get_u16_le_add()?;
+        let ctrl_run = payload.try_get_u8()? == 1;
+        Ok(Box::new(OpLoc {
             op: Op::Op2,
             id,
             x,
             y,
             ctrl_run,
-        })
+        }))
     }
 };
 
@@ -55,17 +55,17 @@ const _: () = {
     const OPCODE: u8 = 10;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let x = payload.get_u16_le();
-        let ctrl_run = payload.get_u8() == 1;
-        let y = payload.get_u16();
-        let id = payload.get_u16_le_add();
-        Box::new(OpLoc {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let x = payload.try_get_u16_le()?;
+        let ctrl_run = payload.try_get_u8()? == 1;
+        let y = payload.try_get_u16()?;
+        let id = payload.try_get_u16_le_add()?;
+        Ok(Box::new(OpLoc {
             op: Op::Op3,
             id,
             x,
             y,
             ctrl_run,
-        })
+        }))
     }
 };
@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 use crate::inbound::Op;
 
 pub struct OpNpc {
@@ -15,14 +15,14 @@ const _: () = {
     const OPCODE: u8 = 13;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let ctrl_run = payload.get_u8_sub() == 1;
-        let npc_index = payload.get_u16();
-        Box::new(OpNpc {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let ctrl_run = payload.try_get_u8_sub()? == 1;
+        let npc_index = payload.try_get_u16()?;
+        Ok(Box::new(OpNpc {
             op: Op::Op1,
             npc_index,
             ctrl_run,
-        })
+        }))
     }
 };
 
@@ -30,14 +30,14 @@ const _: () = {
     const OPCODE: u8 = 30;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let ctrl_run = payload.get_u8_sub() == 1;
-        let npc_index = payload.get_u16_le();
-        Box::new(OpNpc {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let ctrl_run = payload.try_get_u8_sub()? == 1;
+        let npc_index = payload.try_get_u16_le()?;
+        Ok(Box::new(OpNpc {
             op: Op::Op2,
             npc_index,
             ctrl_run,
-        })
+        }))
     }
 };
 
@@ -45,13 +45,13 @@ const _: () = {
     const OPCODE: u8 = 31;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let npc_index = payload.get_u16_le_add();
-        let ctrl_run = payload.get_u8_sub() == 1;
-        Box::new(OpNpc {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let npc_index = payload.try_get_u16_le_add()?;
+        let ctrl_run = payload.try_get_u8_sub()? == 1;
+        Ok(Box::new(OpNpc {
             op: Op::Op3,
             npc_index,
             ctrl_run,
-        })
+        }))
     }
 };
@@ -2,20 +2,41 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder, SelectedComponent, decode_selected};
 
 pub struct OpNpcT {
     pub npc_index: u16,
     pub ctrl_run: bool,
+    pub selected: Option<SelectedComponent>,
 }
 
 const _: () = {
     const OPCODE: u8 = 35;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let ctrl_run = payload.get_u8_sub() == 1;
-        let npc_index = payload.get_u16();
-        Box::new(OpNpcT { npc_index, ctrl_run })
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let ctrl_run = payload.try_get_u8_sub()? == 1;
+        let npc_index = payload.try_get_u16()?;
+        Ok(Box::new(OpNpcT {
+            npc_index,
+            ctrl_run,
+            selected: None,
+        }))
+    }
+};
+
+const _: () = {
+    const OPCODE: u8 = 33;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let npc_index = payload.try_get_u16_le()?;
+        let ctrl_run = payload.try_get_u8_add()? == 1;
+        let selected = decode_selected(&mut payload)?;
+        Ok(Box::new(OpNpcT {
+            npc_index,
+            ctrl_run,
+            selected: Some(selected),
+        }))
     }
 };
@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 pub struct OpObj {
     pub obj_id: u16,
@@ -15,12 +15,12 @@ const _: () = {
     const OPCODE: u8 = 80;
 
     #[message_decoder]
-    fn decode_obj_click(mut payload: Bytes) -> IncomingMessage {
-        let obj_id = payload.get_u16_add();
-        let ctrl_run = payload.get_u8_neg() != 0;
-        let x = payload.get_u16();
-        let y = payload.get_u16_le_add();
+    fn decode_obj_click(mut payload: Bytes) -> DecodeResult {
+        let obj_id = payload.try_get_u16_add()?;
+        let ctrl_run = payload.try_get_u8_neg()? != 0;
+        let x = payload.try_get_u16()?;
+        let y = payload.try_get_u16_le_add()?;
 
-        Box::new(OpObj { obj_id, ctrl_run, x, y })
+        Ok(Box::new(OpObj { obj_id, ctrl_run, x, y }))
     }
 };
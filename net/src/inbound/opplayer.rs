@@ -2,7 +2,7 @@ use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 use util::BufExt;
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 use crate::inbound::Op;
 
 pub struct OpPlayer {
@@ -15,14 +15,14 @@ const _: () = {
     const OPCODE: u8 = 40;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let player_index = payload.get_u16_add();
-        let ctrl_run = payload.get_u8() == 1;
-        Box::new(OpPlayer {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let player_index = payload.try_get_u16_add()?;
+        let ctrl_run = payload.try_get_u8()? == 1;
+        Ok(Box::new(OpPlayer {
             op: Op::Op1,
             player_index,
             ctrl_run,
-        })
+        }))
     }
 };
 
@@ -30,14 +30,14 @@ const _: () = {
     const OPCODE: u8 = 41;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let player_index = payload.get_u16_add();
-        let ctrl_run = payload.get_u8() == 1;
-        Box::new(OpPlayer {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let player_index = payload.try_get_u16_add()?;
+        let ctrl_run = payload.try_get_u8()? == 1;
+        Ok(Box::new(OpPlayer {
             op: Op::Op2,
             player_index,
             ctrl_run,
-        })
+        }))
     }
 };
 
@@ -45,13 +45,13 @@ const _: () = {
     const OPCODE: u8 = 65;
 
     #[message_decoder]
-    fn decode(mut payload: Bytes) -> IncomingMessage {
-        let ctrl_run = payload.get_u8() == 1;
-        let player_index = payload.get_u16_le_add();
-        Box::new(OpPlayer {
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let ctrl_run = payload.try_get_u8()? == 1;
+        let player_index = payload.try_get_u16_le_add()?;
+        Ok(Box::new(OpPlayer {
             op: Op::Op4,
             player_index,
             ctrl_run,
-        })
+        }))
     }
 };
@@ -0,0 +1,27 @@
+use macros::message_decoder;
+use tokio_util::bytes::{Buf, Bytes};
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder, SelectedComponent, decode_selected};
+
+pub struct OpPlayerT {
+    pub player_index: u16,
+    pub ctrl_run: bool,
+    pub selected: SelectedComponent,
+}
+
+const _: () = {
+    const OPCODE: u8 = 34;
+
+    #[message_decoder]
+    fn decode(mut payload: Bytes) -> DecodeResult {
+        let player_index = payload.try_get_u16_le()?;
+        let ctrl_run = payload.try_get_u8_add()? == 1;
+        let selected = decode_selected(&mut payload)?;
+        Ok(Box::new(OpPlayerT {
+            player_index,
+            ctrl_run,
+            selected,
+        }))
+    }
+};
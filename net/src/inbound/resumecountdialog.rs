@@ -1,7 +1,7 @@
 use macros::message_decoder;
 use tokio_util::bytes::{Buf, Bytes};
 
-use super::{InboundDecoder, IncomingMessage};
+use super::{DecodeResult, InboundDecoder};
 
 #[derive(Debug)]
 pub struct ResumeCountDialog {
@@ -12,7 +12,9 @@ const _: () = {
     const OPCODE: u8 = 81;
 
     #[message_decoder]
-    fn decode(mut p: Bytes) -> IncomingMessage {
-        Box::new(ResumeCountDialog { value: p.get_u32() })
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(ResumeCountDialog {
+            value: p.try_get_u32()?,
+        }))
     }
 };
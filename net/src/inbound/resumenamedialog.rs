@@ -0,0 +1,19 @@
+use macros::message_decoder;
+use tokio_util::bytes::Bytes;
+use util::BufExt;
+
+use super::{DecodeResult, InboundDecoder};
+
+#[derive(Debug)]
+pub struct ResumeNameDialog {
+    pub name: String,
+}
+
+const _: () = {
+    const OPCODE: u8 = 63;
+
+    #[message_decoder]
+    fn decode(mut p: Bytes) -> DecodeResult {
+        Ok(Box::new(ResumeNameDialog { name: p.get_string() }))
+    }
+};
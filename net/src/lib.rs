@@ -9,17 +9,23 @@ mod outbound;
 mod service;
 mod session;
 
-pub use config::TcpConfig;
+pub use config::{TcpConfig, WorldConfig};
 pub use error::SessionError;
 pub use inbound::{
-    ClientCheat, ExamLoc, IfButton, IfDialogContinue, IfMoveSlot, IfSubClosed, Inbox, InboxExt, IncomingMessage,
-    MessagePublic, MoveClick, Op, OpLoc, OpNpc, OpNpcT, OpObj, OpPlayer, ResumeCountDialog,
+    ClanJoinChat, ClanKickUser, ClanSetRank, ClientCheat, ExamLoc, FriendlistAdd, FriendlistDel, IfButton, IfButtonT,
+    IfDialogContinue, IfMoveSlot, IfSubClosed, IgnorelistAdd, IgnorelistDel, Inbox, InboxExt, IncomingMessage,
+    MessagePrivate, MessagePublic, MoveClick, Op, OpLoc, OpNpc, OpNpcT, OpObj, OpPlayer, OpPlayerT, ResumeCountDialog,
+    ResumeNameDialog, SelectedComponent, SetChatFilterSettings,
+};
+pub use message::{
+    CountryFlag, Encodable, Frame, LoginOutcome, LoginRequest, LoginSuccess, Prefix, StatusCode, WorldFlag,
 };
-pub use message::{Encodable, Frame, LoginOutcome, LoginRequest, LoginSuccess, Prefix, StatusCode};
 pub use outbound::{
-    IfCloseSub, IfEvents, IfOpenSub, IfOpenTop, IfSetAnim, IfSetEvents, IfSetNpcHead, IfSetPlayerHead, IfSetText,
-    InvEntry, InvType, LocAddChange, LocDel, Logout, MapProjAnim, MessageGame, MidiJingle, MinimapToggle, ObjAdd,
-    ObjCount, ObjDel, Outbox, OutboxExt, RebuildNormal, RunClientScript, ScriptArg, SetPlayerOp, UpdateInvFull,
-    UpdateRunEnergy, UpdateStat, VarbitLarge, VarbitSmall, VarcLarge, VarcSmall, VarpLarge, VarpSmall, ZoneFrame,
+    ChatFilterSettingsPrivateChat, ClanMemberEntry, FriendEntry, IfCloseSub, IfEvents, IfOpenSub, IfOpenTop, IfSetAnim,
+    IfSetEvents, IfSetNpcHead, IfSetPlayerHead, IfSetText, InvEntry, InvType, LeaveClanChannel, LocAddChange, LocDel,
+    Logout, MapProjAnim, MessageClanChannel, MessageGame, MessagePrivateEcho, MessagePrivateFrom, MidiJingle,
+    MinimapToggle, ObjAdd, ObjCount, ObjDel, Outbox, OutboxExt, RebuildNormal, RunClientScript, ScriptArg, SetPlayerOp,
+    UpdateClanChannel, UpdateFriendList, UpdateIgnoreList, UpdateInvFull, UpdateRunEnergy, UpdateStat, VarbitLarge,
+    VarbitSmall, VarcLarge, VarcSmall, VarpLarge, VarpSmall, ZoneFrame,
 };
-pub use service::{LoginService, TcpService};
+pub use service::{LoginService, TcpService, WorldListService};
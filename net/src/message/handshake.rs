@@ -23,6 +23,7 @@ impl HandshakeType {
 pub enum HandshakeResponse {
     Success = 0,
     OutOfDate = 6,
+    Rejected = 11,
 }
 
 #[derive(Debug)]
@@ -30,6 +31,7 @@ pub enum HandshakeInbound {
     Js5 { client_version: u32 },
     WorldList { full_update: bool },
     Login { hash: u8 },
+    Unknown { opcode: u8 },
 }
 
 pub enum HandshakeOutbound {
@@ -61,6 +61,10 @@ pub(crate) enum RequestOpcode {
 pub enum Js5Inbound {
     FileRequest(FileRequest),
     EncryptionKey(u8),
+    LoggedIn,
+    LoggedOut,
+    Connected,
+    Disconnected,
 }
 
 #[derive(Debug)]
@@ -13,7 +13,11 @@ pub enum StatusCode {
     AccountDisabled = 4,
     AlreadyOnline = 5,
     GameUpdated = 6,
+    WorldFull = 7,
+    LoginServerOffline = 8,
     BadSessionId = 10,
+    TooManyAttempts = 16,
+    AddressBlocked = 26,
 }
 
 #[derive(Debug)]
@@ -23,6 +27,7 @@ pub struct LoginRequest {
     pub crc: [u32; 31],
     pub client_key: i64,
     pub server_key: i64,
+    pub reconnecting: bool,
     pub username: String,
     pub password: String,
 }
@@ -40,7 +45,11 @@ pub enum LoginOutcome {
     AccountDisabled,
     AlreadyOnline,
     GameUpdated,
+    WorldFull,
+    LoginServerOffline,
     BadSessionId,
+    TooManyAttempts,
+    AddressBlocked,
 }
 
 #[derive(Debug)]
@@ -91,7 +100,11 @@ impl LoginOutcome {
             LoginOutcome::AccountDisabled => StatusCode::AccountDisabled,
             LoginOutcome::AlreadyOnline => StatusCode::AlreadyOnline,
             LoginOutcome::GameUpdated => StatusCode::GameUpdated,
+            LoginOutcome::WorldFull => StatusCode::WorldFull,
+            LoginOutcome::LoginServerOffline => StatusCode::LoginServerOffline,
             LoginOutcome::BadSessionId => StatusCode::BadSessionId,
+            LoginOutcome::TooManyAttempts => StatusCode::TooManyAttempts,
+            LoginOutcome::AddressBlocked => StatusCode::AddressBlocked,
             LoginOutcome::Success(_) => StatusCode::OK,
         }
     }
@@ -9,4 +9,5 @@ pub(crate) use handshake::{HandshakeInbound, HandshakeOutbound, HandshakeRespons
 pub(crate) use js5::{FileRequest, Js5Inbound, Js5Outbound, PriorityRequest, RequestOpcode};
 pub(crate) use login::{LoginInbound, LoginOutbound, LoginState};
 pub use login::{LoginOutcome, LoginRequest, LoginResponse, LoginSuccess, StatusCode};
-pub(crate) use worldlist::{Country, CountryFlag, World, WorldFlag, WorldListOutbound};
+pub(crate) use worldlist::{Country, World, WorldListOutbound};
+pub use worldlist::{CountryFlag, WorldFlag};
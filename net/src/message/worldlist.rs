@@ -1,8 +1,10 @@
 use bitflags::bitflags;
 use num_enum::IntoPrimitive;
+use serde::Deserialize;
 
 bitflags! {
-    #[derive(Debug, Clone, Copy)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+    #[serde(transparent)]
     pub struct WorldFlag: u32 {
         const MEMBERS = 0x1;
         const QUICK_CHAT = 0x2;
@@ -22,7 +24,8 @@ pub struct World {
     pub player_count: u16,
 }
 
-#[derive(Debug, Copy, Clone, IntoPrimitive)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, IntoPrimitive, Deserialize)]
+#[serde(rename_all = "lowercase")]
 #[repr(u16)]
 pub enum CountryFlag {
     Australia = 0x10,
@@ -40,6 +43,26 @@ pub enum CountryFlag {
     Usa = 0xE1,
 }
 
+impl CountryFlag {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CountryFlag::Australia => "Australia",
+            CountryFlag::Belgium => "Belgium",
+            CountryFlag::Brazil => "Brazil",
+            CountryFlag::Canada => "Canada",
+            CountryFlag::Denmark => "Denmark",
+            CountryFlag::Finland => "Finland",
+            CountryFlag::Ireland => "Ireland",
+            CountryFlag::Uk => "UK",
+            CountryFlag::Mexico => "Mexico",
+            CountryFlag::Netherlands => "Netherlands",
+            CountryFlag::Norway => "Norway",
+            CountryFlag::Sweden => "Sweden",
+            CountryFlag::Usa => "USA",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Country {
     pub flag: CountryFlag,
@@ -0,0 +1,82 @@
+use tokio_util::bytes::{BufMut, Bytes, BytesMut};
+use util::BytesMutExt;
+
+use crate::{Encodable, Frame, Prefix};
+
+#[derive(Debug, Clone)]
+pub struct ClanMemberEntry {
+    pub name: String,
+    pub world: u16,
+    pub rank: i8,
+}
+
+pub struct UpdateClanChannel {
+    pub owner: String,
+    pub name: String,
+    pub kick_rank: i8,
+    pub members: Vec<ClanMemberEntry>,
+}
+
+impl Encodable for UpdateClanChannel {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        buf.put_string(&self.owner);
+        buf.put_string(&self.name);
+        buf.put_i8(self.kick_rank);
+        buf.put_u8(self.members.len() as u8);
+        for member in &self.members {
+            buf.put_string(&member.name);
+            buf.put_u16(member.world);
+            buf.put_i8(member.rank);
+            buf.put_string(&format!("World {}", member.world));
+        }
+
+        Frame {
+            opcode: 82,
+            prefix: Prefix::Short,
+            payload: buf.freeze(),
+        }
+    }
+}
+
+/// An empty channel update, which the client treats as having left.
+pub struct LeaveClanChannel;
+
+impl Encodable for LeaveClanChannel {
+    fn encode(self) -> Frame {
+        Frame {
+            opcode: 82,
+            prefix: Prefix::Short,
+            payload: Bytes::new(),
+        }
+    }
+}
+
+pub struct MessageClanChannel {
+    pub from: String,
+    pub channel: String,
+    pub message_id: u64,
+    pub rights: u8,
+    pub text_len: usize,
+    pub payload: Vec<u8>,
+}
+
+impl Encodable for MessageClanChannel {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        buf.put_u8(0);
+        buf.put_string(&self.from);
+        buf.put_string(&self.channel);
+        buf.put_u16((self.message_id >> 24) as u16);
+        buf.put_u24((self.message_id & 0xffffff) as u32);
+        buf.put_u8(self.rights);
+        buf.put_smart(self.text_len as u16);
+        buf.put_slice(&self.payload);
+
+        Frame {
+            opcode: 54,
+            prefix: Prefix::Byte,
+            payload: buf.freeze(),
+        }
+    }
+}
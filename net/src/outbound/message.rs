@@ -5,6 +5,7 @@ use crate::{Encodable, Frame, Prefix};
 
 pub struct MessageGame {
     pub msg_type: u16,
+    pub name: Option<String>,
     pub text: String,
 }
 
@@ -13,7 +14,13 @@ impl Encodable for MessageGame {
         let mut buf = BytesMut::new();
         buf.put_smart(self.msg_type);
         buf.put_u32(0);
-        buf.put_u8(0);
+        match &self.name {
+            Some(name) => {
+                buf.put_u8(1);
+                buf.put_string(name);
+            }
+            None => buf.put_u8(0),
+        }
         buf.put_string(&self.text);
 
         Frame {
@@ -1,3 +1,4 @@
+mod clan;
 mod ifclosesub;
 mod ifevents;
 mod ifopensub;
@@ -18,11 +19,13 @@ mod projanim;
 mod rebuild;
 mod runenergy;
 mod runscript;
+mod social;
 mod stat;
 mod varclient;
 mod varp;
 mod zone;
 
+pub use clan::{ClanMemberEntry, LeaveClanChannel, MessageClanChannel, UpdateClanChannel};
 pub use ifclosesub::IfCloseSub;
 pub use ifevents::{IfEvents, IfSetEvents};
 pub use ifopensub::IfOpenSub;
@@ -43,6 +46,10 @@ pub use projanim::MapProjAnim;
 pub use rebuild::RebuildNormal;
 pub use runenergy::UpdateRunEnergy;
 pub use runscript::{RunClientScript, ScriptArg};
+pub use social::{
+    ChatFilterSettingsPrivateChat, FriendEntry, MessagePrivateEcho, MessagePrivateFrom, UpdateFriendList,
+    UpdateIgnoreList,
+};
 pub use stat::UpdateStat;
 use tokio::sync::mpsc;
 pub use varclient::{VarcLarge, VarcSmall};
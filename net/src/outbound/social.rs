@@ -0,0 +1,121 @@
+use tokio_util::bytes::{BufMut, BytesMut};
+use util::BytesMutExt;
+
+use crate::{Encodable, Frame, Prefix};
+
+#[derive(Debug, Clone)]
+pub struct FriendEntry {
+    pub name: String,
+    pub world: u16,
+}
+
+pub struct UpdateFriendList {
+    pub friends: Vec<FriendEntry>,
+}
+
+impl Encodable for UpdateFriendList {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        for friend in &self.friends {
+            buf.put_u8(0);
+            buf.put_string(&friend.name);
+            buf.put_string("");
+            buf.put_u16(friend.world);
+            buf.put_u8(0);
+            if friend.world > 0 {
+                buf.put_string(&format!("World {}", friend.world));
+                buf.put_u8(0);
+            }
+        }
+
+        Frame {
+            opcode: 62,
+            prefix: Prefix::Short,
+            payload: buf.freeze(),
+        }
+    }
+}
+
+pub struct UpdateIgnoreList {
+    pub names: Vec<String>,
+}
+
+impl Encodable for UpdateIgnoreList {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        buf.put_u8(self.names.len() as u8);
+        for name in &self.names {
+            buf.put_string(name);
+            buf.put_string("");
+        }
+
+        Frame {
+            opcode: 55,
+            prefix: Prefix::Short,
+            payload: buf.freeze(),
+        }
+    }
+}
+
+pub struct MessagePrivateFrom {
+    pub from: String,
+    pub message_id: u64,
+    pub rights: u8,
+    pub text_len: usize,
+    pub payload: Vec<u8>,
+}
+
+impl Encodable for MessagePrivateFrom {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        buf.put_u8(0);
+        buf.put_string(&self.from);
+        buf.put_u16((self.message_id >> 24) as u16);
+        buf.put_u24((self.message_id & 0xffffff) as u32);
+        buf.put_u8(self.rights);
+        buf.put_smart(self.text_len as u16);
+        buf.put_slice(&self.payload);
+
+        Frame {
+            opcode: 70,
+            prefix: Prefix::Short,
+            payload: buf.freeze(),
+        }
+    }
+}
+
+pub struct MessagePrivateEcho {
+    pub to: String,
+    pub text_len: usize,
+    pub payload: Vec<u8>,
+}
+
+impl Encodable for MessagePrivateEcho {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        buf.put_string(&self.to);
+        buf.put_smart(self.text_len as u16);
+        buf.put_slice(&self.payload);
+
+        Frame {
+            opcode: 71,
+            prefix: Prefix::Short,
+            payload: buf.freeze(),
+        }
+    }
+}
+
+pub struct ChatFilterSettingsPrivateChat(pub u8);
+
+impl Encodable for ChatFilterSettingsPrivateChat {
+    fn encode(self) -> Frame {
+        let mut buf = BytesMut::new();
+        buf.put_u8(self.0);
+
+        Frame {
+            opcode: 56,
+            prefix: Prefix::Fixed,
+            payload: buf.freeze(),
+        }
+    }
+}
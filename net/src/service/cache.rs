@@ -1,18 +1,28 @@
 use std::sync::Arc;
 
 use filesystem::{Cache, CacheResult};
+use tokio::sync::{Semaphore, SemaphorePermit};
 
 use crate::message::FileRequest;
 
 pub struct CacheService {
     cache: Arc<Cache>,
     checksum: Vec<u8>,
+    prefetch_slots: Semaphore,
 }
 
 impl CacheService {
-    pub fn new(cache: Arc<Cache>) -> anyhow::Result<Self> {
+    pub fn new(cache: Arc<Cache>, prefetch_workers: usize) -> anyhow::Result<Self> {
         let checksum = filesystem::build_checksum_table(&cache)?;
-        Ok(Self { cache, checksum })
+        Ok(Self {
+            cache,
+            checksum,
+            prefetch_slots: Semaphore::new(prefetch_workers.max(1)),
+        })
+    }
+
+    pub async fn acquire_prefetch_slot(&self) -> Option<SemaphorePermit<'_>> {
+        self.prefetch_slots.acquire().await.ok()
     }
 
     pub fn get_file(&self, request: &FileRequest) -> CacheResult<Vec<u8>> {
@@ -0,0 +1,152 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+const MAX_BACKOFF_SHIFT: u32 = 6;
+
+pub(crate) struct ConnectionLimiter {
+    max_per_ip: usize,
+    connections: Mutex<HashMap<IpAddr, usize>>,
+}
+
+pub(crate) struct ConnectionPermit {
+    ip: IpAddr,
+    limiter: Arc<ConnectionLimiter>,
+}
+
+impl ConnectionLimiter {
+    pub fn new(max_per_ip: usize) -> Arc<Self> {
+        Arc::new(Self {
+            max_per_ip,
+            connections: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn acquire(self: &Arc<Self>, ip: IpAddr) -> Option<ConnectionPermit> {
+        let mut connections = self.connections.lock().unwrap();
+        let count = connections.entry(ip).or_insert(0);
+        if *count >= self.max_per_ip {
+            return None;
+        }
+
+        *count += 1;
+        Some(ConnectionPermit {
+            ip,
+            limiter: Arc::clone(self),
+        })
+    }
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        let mut connections = self.limiter.connections.lock().unwrap();
+        if let Some(count) = connections.get_mut(&self.ip) {
+            *count -= 1;
+            if *count == 0 {
+                connections.remove(&self.ip);
+            }
+        }
+    }
+}
+
+struct LoginAttempts {
+    failures: u32,
+    last_failure: Instant,
+}
+
+pub(crate) struct LoginThrottle {
+    max_failures: u32,
+    backoff: Duration,
+    attempts: Mutex<HashMap<IpAddr, LoginAttempts>>,
+}
+
+impl LoginThrottle {
+    pub fn new(max_failures: u32, backoff: Duration) -> Self {
+        Self {
+            max_failures,
+            backoff,
+            attempts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_blocked(&self, ip: IpAddr) -> bool {
+        let mut attempts = self.attempts.lock().unwrap();
+        let retention = self.backoff * (1 << MAX_BACKOFF_SHIFT);
+        attempts.retain(|_, a| a.last_failure.elapsed() < retention);
+
+        attempts
+            .get(&ip)
+            .and_then(|a| self.delay(a.failures).map(|delay| a.last_failure.elapsed() < delay))
+            .unwrap_or(false)
+    }
+
+    pub fn record_failure(&self, ip: IpAddr) {
+        let mut attempts = self.attempts.lock().unwrap();
+        let entry = attempts.entry(ip).or_insert(LoginAttempts {
+            failures: 0,
+            last_failure: Instant::now(),
+        });
+        entry.failures += 1;
+        entry.last_failure = Instant::now();
+
+        if let Some(delay) = self.delay(entry.failures) {
+            tracing::warn!(%ip, failures = entry.failures, delay_secs = delay.as_secs(), "Login Throttled");
+        }
+    }
+
+    pub fn clear(&self, ip: IpAddr) {
+        self.attempts.lock().unwrap().remove(&ip);
+    }
+
+    fn delay(&self, failures: u32) -> Option<Duration> {
+        let excess = failures.checked_sub(self.max_failures)?;
+        Some(self.backoff * (1 << excess.min(MAX_BACKOFF_SHIFT)))
+    }
+}
+
+pub(crate) struct BandwidthLimiter {
+    bytes_per_sec: u64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl BandwidthLimiter {
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec,
+            tokens: bytes_per_sec as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    pub fn delay(&mut self) -> Duration {
+        if self.bytes_per_sec == 0 {
+            return Duration::ZERO;
+        }
+
+        self.refill();
+        match self.tokens < 0.0 {
+            true => Duration::from_secs_f64(-self.tokens / self.bytes_per_sec as f64),
+            false => Duration::ZERO,
+        }
+    }
+
+    pub fn consume(&mut self, bytes: usize) {
+        if self.bytes_per_sec == 0 {
+            return;
+        }
+
+        self.refill();
+        self.tokens -= bytes as f64;
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.bytes_per_sec as f64).min(self.bytes_per_sec as f64);
+        self.last_refill = now;
+    }
+}
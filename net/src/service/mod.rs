@@ -1,7 +1,12 @@
 mod cache;
+mod limiter;
 mod login;
 mod tcp;
+mod worldlist;
 
 pub(crate) use cache::CacheService;
+pub(crate) use limiter::{BandwidthLimiter, ConnectionLimiter, LoginThrottle};
 pub use login::LoginService;
 pub use tcp::TcpService;
+pub(crate) use worldlist::WorldDirectory;
+pub use worldlist::WorldListService;
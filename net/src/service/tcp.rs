@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use filesystem::Cache;
 use tokio::{
@@ -6,29 +6,71 @@ use tokio::{
     sync::{Semaphore, oneshot},
 };
 use tokio_util::task::TaskTracker;
+use util::RsaKeyPair;
 
-use crate::{LoginService, config::TcpConfig, service::cache::CacheService, session::Session};
+use crate::{
+    LoginService, WorldListService,
+    config::TcpConfig,
+    service::{ConnectionLimiter, LoginThrottle, WorldDirectory, cache::CacheService},
+    session::{Session, SessionContext},
+};
 
 pub struct TcpService {
     config: TcpConfig,
     cache: Arc<Cache>,
     login_service: Arc<dyn LoginService>,
+    rsa_key: Arc<RsaKeyPair>,
+    world_directory: Arc<WorldDirectory>,
+    client_version: u32,
 }
 
 impl TcpService {
-    pub fn new(config: TcpConfig, cache: Arc<Cache>, login_service: Arc<dyn LoginService>) -> anyhow::Result<Self> {
+    pub fn new(
+        config: TcpConfig,
+        cache: Arc<Cache>,
+        login_service: Arc<dyn LoginService>,
+        world_list_service: Arc<dyn WorldListService>,
+        client_version: u32,
+    ) -> anyhow::Result<Self> {
+        if !config.rsa_key.exists() {
+            anyhow::bail!(
+                "RSA key {} not found; generate one with `cargo run -p tools --bin rsa-keygen -- {}`",
+                config.rsa_key.display(),
+                config.rsa_key.display()
+            );
+        }
+        let rsa_key = Arc::new(RsaKeyPair::load(&config.rsa_key)?);
+        let world_directory = Arc::new(WorldDirectory::new(&config.worlds, world_list_service));
+
         Ok(Self {
             config,
             cache,
             login_service,
+            rsa_key,
+            world_directory,
+            client_version,
         })
     }
 
     async fn run(&self, on_ready: Option<oneshot::Sender<()>>, tracker: TaskTracker) -> anyhow::Result<()> {
         let listener = TcpListener::bind(self.config.bind_addr).await?;
         let semaphore = Arc::new(Semaphore::new(self.config.max_connections));
-        let cache_service = Arc::new(CacheService::new(self.cache.clone())?);
-        let login_service = Arc::clone(&self.login_service);
+        let limiter = ConnectionLimiter::new(self.config.max_connections_per_ip);
+        let context = Arc::new(SessionContext {
+            cache_service: Arc::new(CacheService::new(self.cache.clone(), self.config.js5_prefetch_workers)?),
+            login_service: Arc::clone(&self.login_service),
+            rsa_key: Arc::clone(&self.rsa_key),
+            world_directory: Arc::clone(&self.world_directory),
+            login_throttle: LoginThrottle::new(
+                self.config.max_login_failures,
+                Duration::from_secs(self.config.login_backoff_secs),
+            ),
+            max_packets_per_tick: self.config.max_packets_per_tick,
+            max_malformed_packets: self.config.max_malformed_packets,
+            js5_prefetch_bytes_per_sec: self.config.js5_prefetch_bytes_per_sec,
+            client_version: self.client_version,
+            tracker: tracker.clone(),
+        });
 
         tracing::info!(addr = %self.config.bind_addr, "Listening");
 
@@ -38,14 +80,25 @@ impl TcpService {
 
         loop {
             let permit = semaphore.clone().acquire_owned().await?;
-            let (socket, _) = listener.accept().await?;
-            let session = Session::new(socket, Arc::clone(&cache_service), Arc::clone(&login_service));
+            let (socket, peer_addr) = listener.accept().await?;
+            let Some(ip_permit) = limiter.acquire(peer_addr.ip()) else {
+                tracing::warn!(addr = %peer_addr, "Connection Limit Exceeded");
+                continue;
+            };
+
+            let session = Session::new(socket, peer_addr, Arc::clone(&context));
 
             tracker.spawn(async move {
-                if let Some(e) = session.run().await.err().filter(|err| !err.is_disconnect()) {
+                if let Some(e) = session
+                    .run()
+                    .await
+                    .err()
+                    .filter(|err| !err.is_disconnect() && !err.is_rejection())
+                {
                     tracing::error!(error = %e, "Session Error");
                 }
 
+                drop(ip_permit);
                 drop(permit);
             });
         }
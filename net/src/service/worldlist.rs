@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::{
+    config::WorldConfig,
+    message::{Country, World, WorldListOutbound},
+};
+
+pub trait WorldListService: Send + Sync {
+    fn player_count(&self, world_id: u16) -> Option<usize>;
+}
+
+pub(crate) struct WorldDirectory {
+    countries: Vec<Country>,
+    worlds: Vec<World>,
+    session_id: u32,
+    service: Arc<dyn WorldListService>,
+}
+
+impl WorldDirectory {
+    pub fn new(configs: &[WorldConfig], service: Arc<dyn WorldListService>) -> Self {
+        let mut countries: Vec<Country> = Vec::new();
+        let mut worlds = Vec::with_capacity(configs.len());
+
+        for config in configs {
+            let location = match countries.iter().position(|c| c.flag == config.country) {
+                Some(location) => location,
+                None => {
+                    countries.push(Country {
+                        flag: config.country,
+                        name: config.country.name().to_string(),
+                    });
+                    countries.len() - 1
+                }
+            };
+
+            worlds.push(World {
+                id: config.id,
+                location: location as u8,
+                flags: config.flags,
+                activity: config.activity.clone(),
+                hostname: config.host.clone(),
+                player_count: 0,
+            });
+        }
+
+        Self {
+            countries,
+            worlds,
+            session_id: rand::random(),
+            service,
+        }
+    }
+
+    pub fn snapshot(&self, full_update: bool) -> WorldListOutbound {
+        let worlds = self
+            .worlds
+            .iter()
+            .map(|world| World {
+                player_count: self.service.player_count(world.id).unwrap_or(0).min(u16::MAX as usize) as u16,
+                ..world.clone()
+            })
+            .collect();
+
+        WorldListOutbound {
+            full_update,
+            countries: match full_update {
+                true => self.countries.clone(),
+                false => Vec::new(),
+            },
+            worlds,
+            session_id: self.session_id,
+        }
+    }
+}
@@ -1,12 +1,14 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 use tokio::net::TcpStream;
+use tokio_util::task::TaskTracker;
+use util::RsaKeyPair;
 
 use crate::{
     LoginService,
     error::SessionError,
     handler::{HandshakeHandler, Js5Handler, LoginHandler, WorldListHandler},
-    service::CacheService,
+    service::{CacheService, LoginThrottle, WorldDirectory},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -16,28 +18,45 @@ pub enum SessionPhase {
     Login { hash: u8 },
 }
 
+pub struct SessionContext {
+    pub cache_service: Arc<CacheService>,
+    pub login_service: Arc<dyn LoginService>,
+    pub rsa_key: Arc<RsaKeyPair>,
+    pub world_directory: Arc<WorldDirectory>,
+    pub login_throttle: LoginThrottle,
+    pub max_packets_per_tick: usize,
+    pub max_malformed_packets: usize,
+    pub js5_prefetch_bytes_per_sec: u64,
+    pub client_version: u32,
+    /// Tracks logouts, which outlive their connection through the reconnect grace
+    /// period while shutdown still waits for them to save.
+    pub tracker: TaskTracker,
+}
+
 pub struct Session {
     stream: TcpStream,
-    cache_service: Arc<CacheService>,
-    login_service: Arc<dyn LoginService>,
+    peer_addr: SocketAddr,
+    context: Arc<SessionContext>,
 }
 
 impl Session {
-    pub fn new(stream: TcpStream, cache_service: Arc<CacheService>, login_service: Arc<dyn LoginService>) -> Self {
+    pub fn new(stream: TcpStream, peer_addr: SocketAddr, context: Arc<SessionContext>) -> Self {
         Self {
             stream,
-            cache_service,
-            login_service,
+            peer_addr,
+            context,
         }
     }
 
     pub async fn run(self) -> anyhow::Result<(), SessionError> {
-        let (stream, phase) = HandshakeHandler::run(self.stream).await?;
+        let (stream, phase) = HandshakeHandler::run(self.stream, self.peer_addr, self.context.client_version).await?;
 
         match phase {
-            SessionPhase::Js5 => Js5Handler::run(stream, self.cache_service).await,
-            SessionPhase::WorldList { full_update } => WorldListHandler::run(stream, full_update).await,
-            SessionPhase::Login { hash } => LoginHandler::run(stream, hash, self.login_service).await,
+            SessionPhase::Js5 => Js5Handler::run(stream, self.context).await,
+            SessionPhase::WorldList { full_update } => {
+                WorldListHandler::run(stream, full_update, Arc::clone(&self.context.world_directory)).await
+            }
+            SessionPhase::Login { hash } => LoginHandler::run(stream, hash, self.peer_addr, self.context).await,
         }
     }
 }
@@ -11,6 +11,7 @@ pub struct Model {
     pub disabled: bool,
     pub created_at: DateTimeWithTimeZone,
     pub last_login: Option<DateTimeWithTimeZone>,
+    pub last_ip: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
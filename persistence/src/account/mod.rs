@@ -1,13 +1,18 @@
 pub(crate) mod entity;
 mod repository;
 
-use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use argon2::{
+    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+    password_hash::{Error as HashError, SaltString},
+};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 pub use repository::AccountRepository;
 pub(crate) use repository::{PgAccountRepository, PgAccountRepositoryParameters};
 
+const PASSWORD_LEN: std::ops::RangeInclusive<usize> = 5..=20;
+
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, TryFromPrimitive, IntoPrimitive)]
 pub enum Rights {
     Standard = 0,
     Moderator = 1,
@@ -21,6 +26,7 @@ pub struct Account {
     pub password_hash: String,
     pub rights: Rights,
     pub disabled: bool,
+    pub last_ip: Option<String>,
 }
 
 impl Account {
@@ -34,3 +40,14 @@ impl Account {
             .unwrap_or(false)
     }
 }
+
+/// Whether `password` is something the client would let a player type in.
+pub fn is_valid_password(password: &str) -> bool {
+    PASSWORD_LEN.contains(&password.len()) && password.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Hashes `password` into the PHC string stored in `accounts.password_hash`.
+pub fn hash_password(password: &str) -> Result<String, HashError> {
+    let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>())?;
+    Ok(Argon2::default().hash_password(password.as_bytes(), &salt)?.to_string())
+}
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use sea_orm::{prelude::Expr, *};
+use sea_orm::{prelude::Expr, sea_query::SimpleExpr, *};
 use shaku::{Component, Interface};
 
 use super::{
@@ -19,6 +19,7 @@ impl TryFrom<entity::Model> for Account {
             password_hash: model.password_hash,
             rights,
             disabled: model.disabled,
+            last_ip: model.last_ip,
         })
     }
 }
@@ -26,7 +27,11 @@ impl TryFrom<entity::Model> for Account {
 #[async_trait]
 pub trait AccountRepository: Interface {
     async fn find_by_username(&self, username: &str) -> Result<Option<Account>, DbErr>;
-    async fn update_last_login(&self, account_id: i64) -> Result<(), DbErr>;
+    async fn update_last_login(&self, account_id: i64, ip: &str) -> Result<(), DbErr>;
+    async fn create(&self, username: &str, password_hash: &str, rights: Rights) -> Result<Account, DbErr>;
+    async fn update_password(&self, account_id: i64, password_hash: &str) -> Result<(), DbErr>;
+    async fn update_rights(&self, account_id: i64, rights: Rights) -> Result<(), DbErr>;
+    async fn set_disabled(&self, account_id: i64, disabled: bool) -> Result<(), DbErr>;
 }
 
 #[derive(Component)]
@@ -47,9 +52,47 @@ impl AccountRepository for PgAccountRepository {
             .transpose()
     }
 
-    async fn update_last_login(&self, account_id: i64) -> Result<(), DbErr> {
+    async fn update_last_login(&self, account_id: i64, ip: &str) -> Result<(), DbErr> {
         AccountEntity::update_many()
             .col_expr(Column::LastLogin, Expr::current_timestamp().into())
+            .col_expr(Column::LastIp, Expr::value(ip))
+            .filter(Column::Id.eq(account_id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create(&self, username: &str, password_hash: &str, rights: Rights) -> Result<Account, DbErr> {
+        let model = entity::ActiveModel {
+            username: Set(username.to_string()),
+            password_hash: Set(password_hash.to_string()),
+            rights: Set(u8::from(rights) as i16),
+            ..Default::default()
+        };
+
+        model.insert(&self.db).await.and_then(Account::try_from)
+    }
+
+    async fn update_password(&self, account_id: i64, password_hash: &str) -> Result<(), DbErr> {
+        self.update(account_id, Column::PasswordHash, Expr::value(password_hash))
+            .await
+    }
+
+    async fn update_rights(&self, account_id: i64, rights: Rights) -> Result<(), DbErr> {
+        self.update(account_id, Column::Rights, Expr::value(u8::from(rights) as i16))
+            .await
+    }
+
+    async fn set_disabled(&self, account_id: i64, disabled: bool) -> Result<(), DbErr> {
+        self.update(account_id, Column::Disabled, Expr::value(disabled)).await
+    }
+}
+
+impl PgAccountRepository {
+    async fn update(&self, account_id: i64, column: Column, value: SimpleExpr) -> Result<(), DbErr> {
+        AccountEntity::update_many()
+            .col_expr(column, value)
             .filter(Column::Id.eq(account_id))
             .exec(&self.db)
             .await?;
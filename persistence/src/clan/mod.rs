@@ -0,0 +1,5 @@
+pub(crate) mod entity;
+mod repository;
+
+pub use repository::{ClanChannelData, ClanRepository};
+pub(crate) use repository::{PgClanRepository, PgClanRepositoryParameters};
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sea_orm::*;
+use shaku::{Component, Interface};
+
+use super::entity::{ban, channel, rank};
+use crate::{account, player::entity::player};
+
+#[derive(Clone)]
+pub struct ClanChannelData {
+    pub owner_id: i64,
+    pub owner: String,
+    pub name: String,
+    pub join_rank: i8,
+    pub talk_rank: i8,
+    pub kick_rank: i8,
+    pub ranks: Vec<(String, i8)>,
+    pub bans: Vec<String>,
+}
+
+#[async_trait]
+pub trait ClanRepository: Interface {
+    async fn find_all(&self) -> Result<Vec<ClanChannelData>, DbErr>;
+    async fn save(&self, data: &ClanChannelData) -> Result<(), DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ClanRepository)]
+pub struct PgClanRepository {
+    #[shaku(default)]
+    db: DatabaseConnection,
+}
+
+#[async_trait]
+impl ClanRepository for PgClanRepository {
+    async fn find_all(&self) -> Result<Vec<ClanChannelData>, DbErr> {
+        let channels = channel::Entity::find().all(&self.db).await?;
+        if channels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let owner_ids: Vec<i64> = channels.iter().map(|c| c.owner_id).collect();
+        let owners: HashMap<i64, String> = player::Entity::find()
+            .filter(player::Column::Id.is_in(owner_ids))
+            .find_also_related(account::entity::Entity)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter_map(|(p, a)| Some((p.id, a?.username)))
+            .collect();
+
+        let mut ranks: HashMap<i64, Vec<(String, i8)>> = HashMap::new();
+        for model in rank::Entity::find().all(&self.db).await? {
+            ranks
+                .entry(model.owner_id)
+                .or_default()
+                .push((model.username, model.rank as i8));
+        }
+
+        let mut bans: HashMap<i64, Vec<String>> = HashMap::new();
+        for model in ban::Entity::find().all(&self.db).await? {
+            bans.entry(model.owner_id).or_default().push(model.username);
+        }
+
+        Ok(channels
+            .into_iter()
+            .filter_map(|c| {
+                Some(ClanChannelData {
+                    owner_id: c.owner_id,
+                    owner: owners.get(&c.owner_id)?.clone(),
+                    name: c.name,
+                    join_rank: c.join_rank as i8,
+                    talk_rank: c.talk_rank as i8,
+                    kick_rank: c.kick_rank as i8,
+                    ranks: ranks.remove(&c.owner_id).unwrap_or_default(),
+                    bans: bans.remove(&c.owner_id).unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+
+    async fn save(&self, data: &ClanChannelData) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        let model = channel::ActiveModel {
+            owner_id: Set(data.owner_id),
+            name: Set(data.name.clone()),
+            join_rank: Set(data.join_rank as i16),
+            talk_rank: Set(data.talk_rank as i16),
+            kick_rank: Set(data.kick_rank as i16),
+        };
+        channel::Entity::insert(model)
+            .on_conflict(
+                sea_query::OnConflict::column(channel::Column::OwnerId)
+                    .update_columns([
+                        channel::Column::Name,
+                        channel::Column::JoinRank,
+                        channel::Column::TalkRank,
+                        channel::Column::KickRank,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&txn)
+            .await?;
+
+        rank::Entity::delete_many()
+            .filter(rank::Column::OwnerId.eq(data.owner_id))
+            .exec(&txn)
+            .await?;
+        if !data.ranks.is_empty() {
+            rank::Entity::insert_many(data.ranks.iter().map(|(username, r)| rank::ActiveModel {
+                owner_id: Set(data.owner_id),
+                username: Set(username.clone()),
+                rank: Set(*r as i16),
+            }))
+            .exec(&txn)
+            .await?;
+        }
+
+        ban::Entity::delete_many()
+            .filter(ban::Column::OwnerId.eq(data.owner_id))
+            .exec(&txn)
+            .await?;
+        if !data.bans.is_empty() {
+            ban::Entity::insert_many(data.bans.iter().map(|username| ban::ActiveModel {
+                owner_id: Set(data.owner_id),
+                username: Set(username.clone()),
+            }))
+            .exec(&txn)
+            .await?;
+        }
+
+        txn.commit().await
+    }
+}
@@ -4,11 +4,15 @@ use shaku::module;
 
 use crate::{
     account::{AccountRepository, PgAccountRepository, PgAccountRepositoryParameters},
+    clan::{ClanRepository, PgClanRepository, PgClanRepositoryParameters},
     config::DatabaseConfig,
     migration::Migrator,
     npc::{NpcConfigRepository, PgNpcConfigRepository, PgNpcConfigRepositoryParameters},
     obj::{ObjConfigRepository, PgObjConfigRepository, PgObjConfigRepositoryParameters},
     player::{PgPlayerRepository, PgPlayerRepositoryParameters, PlayerRepository},
+    population::{PgPopulationRepository, PgPopulationRepositoryParameters, PopulationRepository},
+    punishment::{PgPunishmentRepository, PgPunishmentRepositoryParameters, PunishmentRepository},
+    social::{PgSocialRepository, PgSocialRepositoryParameters, SocialRepository},
 };
 
 pub trait PersistenceModuleInterface:
@@ -16,12 +20,25 @@ pub trait PersistenceModuleInterface:
     + shaku::HasComponent<dyn PlayerRepository>
     + shaku::HasComponent<dyn ObjConfigRepository>
     + shaku::HasComponent<dyn NpcConfigRepository>
+    + shaku::HasComponent<dyn SocialRepository>
+    + shaku::HasComponent<dyn ClanRepository>
+    + shaku::HasComponent<dyn PunishmentRepository>
+    + shaku::HasComponent<dyn PopulationRepository>
 {
 }
 
 module! {
     pub PersistenceModule: PersistenceModuleInterface {
-        components = [PgAccountRepository, PgPlayerRepository, PgObjConfigRepository, PgNpcConfigRepository],
+        components = [
+            PgAccountRepository,
+            PgPlayerRepository,
+            PgObjConfigRepository,
+            PgNpcConfigRepository,
+            PgSocialRepository,
+            PgClanRepository,
+            PgPunishmentRepository,
+            PgPopulationRepository
+        ],
         providers = []
     }
 }
@@ -37,7 +54,11 @@ pub async fn connect(config: &DatabaseConfig) -> anyhow::Result<PersistenceModul
         .with_component_parameters::<PgAccountRepository>(PgAccountRepositoryParameters { db: db.clone() })
         .with_component_parameters::<PgPlayerRepository>(PgPlayerRepositoryParameters { db: db.clone() })
         .with_component_parameters::<PgObjConfigRepository>(PgObjConfigRepositoryParameters { db: db.clone() })
-        .with_component_parameters::<PgNpcConfigRepository>(PgNpcConfigRepositoryParameters { db })
+        .with_component_parameters::<PgNpcConfigRepository>(PgNpcConfigRepositoryParameters { db: db.clone() })
+        .with_component_parameters::<PgSocialRepository>(PgSocialRepositoryParameters { db: db.clone() })
+        .with_component_parameters::<PgClanRepository>(PgClanRepositoryParameters { db: db.clone() })
+        .with_component_parameters::<PgPunishmentRepository>(PgPunishmentRepositoryParameters { db: db.clone() })
+        .with_component_parameters::<PgPopulationRepository>(PgPopulationRepositoryParameters { db })
         .build();
 
     Ok(module)
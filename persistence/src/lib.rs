@@ -1,18 +1,26 @@
 mod account;
+mod clan;
 mod config;
 mod database;
 mod migration;
 mod npc;
 mod obj;
 mod player;
+mod population;
+mod punishment;
+mod social;
 
-pub use account::{Account, AccountRepository, Rights};
+pub use account::{Account, AccountRepository, Rights, hash_password, is_valid_password};
+pub use clan::{ClanChannelData, ClanRepository};
 pub use config::DatabaseConfig;
 pub use database::{PersistenceModule, PersistenceModuleInterface, connect};
-pub use npc::{FaceDirection, NpcConfig, NpcConfigRepository, NpcSpawn};
+pub use npc::{DropCategory, FaceDirection, NpcConfig, NpcConfigRepository, NpcDrop, NpcSpawn, SharedDrop};
 pub use obj::{
     AmmoType as DbAmmoType, ObjAmmoConfig, ObjConfigRepository, ObjRangedConfig, ObjStatConfig, ObjWeaponConfig,
     ObjWearConfig, WeaponCategory as DbWeaponCategory, WearFlag as DbWearFlag, WearPos as DbWearPos,
 };
 pub use player::{PlayerData, PlayerRepository};
+pub use population::PopulationRepository;
+pub use punishment::{NewPunishment, Punishment, PunishmentKind, PunishmentRepository};
 pub use shaku;
+pub use social::SocialRepository;
@@ -0,0 +1,158 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_query::extension::postgres::Type};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const SHARED_DROPS: &str = "INSERT INTO shared_drops \
+    (table_name, obj_id, shared_table, min_amount, max_amount, noted, numerator, denominator) VALUES \
+    ('gem', 1623, NULL, 1, 1, false, 32, 128), \
+    ('gem', 1621, NULL, 1, 1, false, 16, 128), \
+    ('gem', 1619, NULL, 1, 1, false, 8, 128), \
+    ('gem', 1452, NULL, 1, 1, false, 3, 128), \
+    ('gem', 1462, NULL, 1, 1, false, 3, 128), \
+    ('gem', 1617, NULL, 1, 1, false, 2, 128), \
+    ('gem', 830, NULL, 5, 5, false, 1, 128), \
+    ('gem', 987, NULL, 1, 1, false, 1, 128), \
+    ('gem', 985, NULL, 1, 1, false, 1, 128), \
+    ('gem', NULL, 'rare', 1, 1, false, 1, 128), \
+    ('rare', 561, NULL, 67, 67, false, 3, 128), \
+    ('rare', 563, NULL, 45, 45, false, 2, 128), \
+    ('rare', 560, NULL, 45, 45, false, 2, 128), \
+    ('rare', 886, NULL, 150, 150, false, 2, 128), \
+    ('rare', 892, NULL, 42, 42, false, 2, 128), \
+    ('rare', 1319, NULL, 1, 1, false, 2, 128), \
+    ('rare', 1373, NULL, 1, 1, false, 3, 128), \
+    ('rare', 1185, NULL, 1, 1, false, 2, 128), \
+    ('rare', 1149, NULL, 1, 1, false, 1, 128), \
+    ('rare', 1201, NULL, 1, 1, false, 1, 128), \
+    ('rare', 995, NULL, 3000, 3000, false, 21, 128), \
+    ('rare', 987, NULL, 1, 1, false, 20, 128), \
+    ('rare', 985, NULL, 1, 1, false, 19, 128), \
+    ('rare', 2363, NULL, 1, 1, false, 5, 128), \
+    ('rare', 1615, NULL, 1, 1, false, 2, 128), \
+    ('rare', 443, NULL, 100, 100, true, 2, 128), \
+    ('rare', 2366, NULL, 1, 1, false, 1, 128), \
+    ('rare', 1249, NULL, 1, 1, false, 1, 128), \
+    ('rare', NULL, 'gem', 1, 1, false, 20, 128)";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(DropCategory::Type)
+                    .values([DropCategory::Always, DropCategory::Main, DropCategory::Tertiary])
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(NpcDrops::Table)
+                    .if_not_exists()
+                    .col(integer(NpcDrops::Id).primary_key().auto_increment().not_null())
+                    .col(integer(NpcDrops::NpcId).not_null())
+                    .col(
+                        ColumnDef::new(NpcDrops::Category)
+                            .custom(DropCategory::Type)
+                            .not_null()
+                            .default("main"),
+                    )
+                    .col(ColumnDef::new(NpcDrops::ObjId).integer().null())
+                    .col(ColumnDef::new(NpcDrops::SharedTable).text().null())
+                    .col(integer(NpcDrops::MinAmount).not_null().default(1))
+                    .col(integer(NpcDrops::MaxAmount).not_null().default(1))
+                    .col(boolean(NpcDrops::Noted).not_null().default(false))
+                    .col(integer(NpcDrops::Numerator).not_null().default(1))
+                    .col(integer(NpcDrops::Denominator).not_null().default(1))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_npc_drops_npc_id")
+                    .table(NpcDrops::Table)
+                    .col(NpcDrops::NpcId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SharedDrops::Table)
+                    .if_not_exists()
+                    .col(integer(SharedDrops::Id).primary_key().auto_increment().not_null())
+                    .col(text(SharedDrops::TableName).not_null())
+                    .col(ColumnDef::new(SharedDrops::ObjId).integer().null())
+                    .col(ColumnDef::new(SharedDrops::SharedTable).text().null())
+                    .col(integer(SharedDrops::MinAmount).not_null().default(1))
+                    .col(integer(SharedDrops::MaxAmount).not_null().default(1))
+                    .col(boolean(SharedDrops::Noted).not_null().default(false))
+                    .col(integer(SharedDrops::Numerator).not_null().default(1))
+                    .col(integer(SharedDrops::Denominator).not_null().default(1))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(SHARED_DROPS)
+            .await
+            .map(|_| ())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SharedDrops::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(NpcDrops::Table).to_owned())
+            .await?;
+        manager
+            .drop_type(Type::drop().name(DropCategory::Type).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DropCategory {
+    #[sea_orm(iden = "drop_category")]
+    Type,
+    Always,
+    Main,
+    Tertiary,
+}
+
+#[derive(DeriveIden)]
+enum NpcDrops {
+    Table,
+    Id,
+    NpcId,
+    Category,
+    ObjId,
+    SharedTable,
+    MinAmount,
+    MaxAmount,
+    Noted,
+    Numerator,
+    Denominator,
+}
+
+#[derive(DeriveIden)]
+enum SharedDrops {
+    Table,
+    Id,
+    TableName,
+    ObjId,
+    SharedTable,
+    MinAmount,
+    MaxAmount,
+    Noted,
+    Numerator,
+    Denominator,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(NpcConfigs::Table)
+                    .add_column(
+                        ColumnDef::new(NpcConfigs::AggroRadius)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(NpcConfigs::ToleranceTicks)
+                            .small_integer()
+                            .not_null()
+                            .default(1000),
+                    )
+                    .add_column(
+                        ColumnDef::new(NpcConfigs::AlwaysAggressive)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(NpcConfigs::Table)
+                    .drop_column(NpcConfigs::AggroRadius)
+                    .drop_column(NpcConfigs::ToleranceTicks)
+                    .drop_column(NpcConfigs::AlwaysAggressive)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum NpcConfigs {
+    Table,
+    AggroRadius,
+    ToleranceTicks,
+    AlwaysAggressive,
+}
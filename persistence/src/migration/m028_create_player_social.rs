@@ -0,0 +1,107 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PlayerFriends::Table)
+                    .if_not_exists()
+                    .col(big_integer(PlayerFriends::PlayerId).not_null())
+                    .col(string_len(PlayerFriends::Username, 12).not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(PlayerFriends::PlayerId)
+                            .col(PlayerFriends::Username),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PlayerFriends::Table, PlayerFriends::PlayerId)
+                            .to(Players::Table, Players::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PlayerIgnores::Table)
+                    .if_not_exists()
+                    .col(big_integer(PlayerIgnores::PlayerId).not_null())
+                    .col(string_len(PlayerIgnores::Username, 12).not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(PlayerIgnores::PlayerId)
+                            .col(PlayerIgnores::Username),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PlayerIgnores::Table, PlayerIgnores::PlayerId)
+                            .to(Players::Table, Players::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Players::Table)
+                    .add_column(
+                        ColumnDef::new(Players::PrivateChat)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Players::Table)
+                    .drop_column(Players::PrivateChat)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PlayerIgnores::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PlayerFriends::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PlayerFriends {
+    Table,
+    PlayerId,
+    Username,
+}
+
+#[derive(DeriveIden)]
+enum PlayerIgnores {
+    Table,
+    PlayerId,
+    Username,
+}
+
+#[derive(DeriveIden)]
+enum Players {
+    Table,
+    Id,
+    PrivateChat,
+}
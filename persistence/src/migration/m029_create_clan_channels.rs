@@ -0,0 +1,130 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClanChannels::Table)
+                    .if_not_exists()
+                    .col(big_integer(ClanChannels::OwnerId).primary_key().not_null())
+                    .col(string_len(ClanChannels::Name, 12).not_null())
+                    .col(small_integer(ClanChannels::JoinRank).not_null().default(-1))
+                    .col(small_integer(ClanChannels::TalkRank).not_null().default(-1))
+                    .col(small_integer(ClanChannels::KickRank).not_null().default(7))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClanChannels::Table, ClanChannels::OwnerId)
+                            .to(Players::Table, Players::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClanRanks::Table)
+                    .if_not_exists()
+                    .col(big_integer(ClanRanks::OwnerId).not_null())
+                    .col(string_len(ClanRanks::Username, 12).not_null())
+                    .col(small_integer(ClanRanks::Rank).not_null())
+                    .primary_key(Index::create().col(ClanRanks::OwnerId).col(ClanRanks::Username))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClanRanks::Table, ClanRanks::OwnerId)
+                            .to(ClanChannels::Table, ClanChannels::OwnerId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ClanBans::Table)
+                    .if_not_exists()
+                    .col(big_integer(ClanBans::OwnerId).not_null())
+                    .col(string_len(ClanBans::Username, 12).not_null())
+                    .primary_key(Index::create().col(ClanBans::OwnerId).col(ClanBans::Username))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ClanBans::Table, ClanBans::OwnerId)
+                            .to(ClanChannels::Table, ClanChannels::OwnerId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Players::Table)
+                    .add_column(ColumnDef::new(Players::ClanChannel).string_len(12).null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Players::Table)
+                    .drop_column(Players::ClanChannel)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ClanBans::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ClanRanks::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ClanChannels::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ClanChannels {
+    Table,
+    OwnerId,
+    Name,
+    JoinRank,
+    TalkRank,
+    KickRank,
+}
+
+#[derive(DeriveIden)]
+enum ClanRanks {
+    Table,
+    OwnerId,
+    Username,
+    Rank,
+}
+
+#[derive(DeriveIden)]
+enum ClanBans {
+    Table,
+    OwnerId,
+    Username,
+}
+
+#[derive(DeriveIden)]
+enum Players {
+    Table,
+    Id,
+    ClanChannel,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Punishments::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Punishments::Id).big_integer())
+                    .col(big_integer(Punishments::AccountId).not_null())
+                    .col(small_integer(Punishments::Kind).not_null())
+                    .col(string_len_null(Punishments::Ip, 45))
+                    .col(text(Punishments::Reason).not_null().default(""))
+                    .col(big_integer_null(Punishments::StaffId))
+                    .col(
+                        timestamp_with_time_zone(Punishments::CreatedAt)
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Punishments::ExpiresAt))
+                    .col(boolean(Punishments::Revoked).not_null().default(false))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Punishments::Table, Punishments::AccountId)
+                            .to(Accounts::Table, Accounts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Punishments::Table, Punishments::StaffId)
+                            .to(Accounts::Table, Accounts::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_punishments_ip")
+                    .table(Punishments::Table)
+                    .col(Punishments::Ip)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Accounts::Table)
+                    .add_column(ColumnDef::new(Accounts::LastIp).string_len(45).null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Accounts::Table)
+                    .drop_column(Accounts::LastIp)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Punishments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Punishments {
+    Table,
+    Id,
+    AccountId,
+    Kind,
+    Ip,
+    Reason,
+    StaffId,
+    CreatedAt,
+    ExpiresAt,
+    Revoked,
+}
+
+#[derive(DeriveIden)]
+enum Accounts {
+    Table,
+    Id,
+    LastIp,
+}
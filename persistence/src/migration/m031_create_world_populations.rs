@@ -0,0 +1,39 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WorldPopulations::Table)
+                    .if_not_exists()
+                    .col(small_integer(WorldPopulations::WorldId).primary_key())
+                    .col(integer(WorldPopulations::PlayerCount).not_null().default(0))
+                    .col(
+                        timestamp_with_time_zone(WorldPopulations::UpdatedAt)
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WorldPopulations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WorldPopulations {
+    Table,
+    WorldId,
+    PlayerCount,
+    UpdatedAt,
+}
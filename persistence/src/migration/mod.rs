@@ -24,6 +24,13 @@ mod m021_add_obj_atk_spotanim;
 mod m022_rename_proj_gfx;
 mod m023_create_player_bank;
 mod m024_add_bank_last_x;
+mod m025_add_prayer_points;
+mod m026_create_npc_drops;
+mod m027_add_npc_aggression;
+mod m028_create_player_social;
+mod m029_create_clan_channels;
+mod m030_create_punishments;
+mod m031_create_world_populations;
 
 pub struct Migrator;
 
@@ -55,6 +62,13 @@ impl MigratorTrait for Migrator {
             Box::new(m022_rename_proj_gfx::Migration),
             Box::new(m023_create_player_bank::Migration),
             Box::new(m024_add_bank_last_x::Migration),
+            Box::new(m025_add_prayer_points::Migration),
+            Box::new(m026_create_npc_drops::Migration),
+            Box::new(m027_add_npc_aggression::Migration),
+            Box::new(m028_create_player_social::Migration),
+            Box::new(m029_create_clan_channels::Migration),
+            Box::new(m030_create_punishments::Migration),
+            Box::new(m031_create_world_populations::Migration),
         ]
     }
 }
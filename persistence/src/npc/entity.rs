@@ -23,6 +23,9 @@ pub mod config {
         pub death_seq: i16,
         pub max_hit: i16,
         pub atk_range: i16,
+        pub aggro_radius: i16,
+        pub tolerance_ticks: i16,
+        pub always_aggressive: bool,
     }
 
     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -74,3 +77,63 @@ pub mod spawn {
 
     impl ActiveModelBehavior for ActiveModel {}
 }
+
+pub mod drop {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+    #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "drop_category")]
+    pub enum DropCategory {
+        #[sea_orm(string_value = "always")]
+        Always,
+        #[sea_orm(string_value = "main")]
+        Main,
+        #[sea_orm(string_value = "tertiary")]
+        Tertiary,
+    }
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "npc_drops")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub npc_id: i32,
+        pub category: DropCategory,
+        pub obj_id: Option<i32>,
+        pub shared_table: Option<String>,
+        pub min_amount: i32,
+        pub max_amount: i32,
+        pub noted: bool,
+        pub numerator: i32,
+        pub denominator: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod shared_drop {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "shared_drops")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub table_name: String,
+        pub obj_id: Option<i32>,
+        pub shared_table: Option<String>,
+        pub min_amount: i32,
+        pub max_amount: i32,
+        pub noted: bool,
+        pub numerator: i32,
+        pub denominator: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
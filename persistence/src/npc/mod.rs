@@ -1,6 +1,6 @@
 pub(crate) mod entity;
 mod repository;
 
-pub use entity::spawn::FaceDirection;
-pub use repository::{NpcConfig, NpcConfigRepository, NpcSpawn};
+pub use entity::{drop::DropCategory, spawn::FaceDirection};
+pub use repository::{NpcConfig, NpcConfigRepository, NpcDrop, NpcSpawn, SharedDrop};
 pub(crate) use repository::{PgNpcConfigRepository, PgNpcConfigRepositoryParameters};
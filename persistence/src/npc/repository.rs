@@ -3,7 +3,7 @@ use sea_orm::*;
 use shaku::{Component, Interface};
 use spawn::FaceDirection;
 
-use super::entity::{config, spawn};
+use super::entity::{config, drop, drop::DropCategory, shared_drop, spawn};
 
 pub struct NpcConfig {
     pub npc_id: u32,
@@ -24,6 +24,9 @@ pub struct NpcConfig {
     pub death_seq: u16,
     pub max_hit: u16,
     pub atk_range: u16,
+    pub aggro_radius: u16,
+    pub tolerance_ticks: u16,
+    pub always_aggressive: bool,
 }
 
 pub struct NpcSpawn {
@@ -36,10 +39,35 @@ pub struct NpcSpawn {
     pub face_direction: FaceDirection,
 }
 
+pub struct NpcDrop {
+    pub npc_id: u32,
+    pub category: DropCategory,
+    pub obj_id: Option<u32>,
+    pub shared_table: Option<String>,
+    pub min_amount: u32,
+    pub max_amount: u32,
+    pub noted: bool,
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+pub struct SharedDrop {
+    pub table_name: String,
+    pub obj_id: Option<u32>,
+    pub shared_table: Option<String>,
+    pub min_amount: u32,
+    pub max_amount: u32,
+    pub noted: bool,
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
 #[async_trait]
 pub trait NpcConfigRepository: Interface {
     async fn find_all_configs(&self) -> Result<Vec<NpcConfig>, DbErr>;
     async fn find_all_spawns(&self) -> Result<Vec<NpcSpawn>, DbErr>;
+    async fn find_all_drops(&self) -> Result<Vec<NpcDrop>, DbErr>;
+    async fn find_all_shared_drops(&self) -> Result<Vec<SharedDrop>, DbErr>;
 }
 
 #[derive(Component)]
@@ -74,6 +102,9 @@ impl NpcConfigRepository for PgNpcConfigRepository {
                 death_seq: m.death_seq as u16,
                 max_hit: m.max_hit as u16,
                 atk_range: m.atk_range as u16,
+                aggro_radius: m.aggro_radius as u16,
+                tolerance_ticks: m.tolerance_ticks as u16,
+                always_aggressive: m.always_aggressive,
             })
             .collect())
     }
@@ -93,4 +124,39 @@ impl NpcConfigRepository for PgNpcConfigRepository {
             })
             .collect())
     }
+
+    async fn find_all_drops(&self) -> Result<Vec<NpcDrop>, DbErr> {
+        let models = drop::Entity::find().all(&self.db).await?;
+        Ok(models
+            .into_iter()
+            .map(|m| NpcDrop {
+                npc_id: m.npc_id as u32,
+                category: m.category,
+                obj_id: m.obj_id.map(|id| id as u32),
+                shared_table: m.shared_table,
+                min_amount: m.min_amount as u32,
+                max_amount: m.max_amount as u32,
+                noted: m.noted,
+                numerator: m.numerator as u32,
+                denominator: m.denominator as u32,
+            })
+            .collect())
+    }
+
+    async fn find_all_shared_drops(&self) -> Result<Vec<SharedDrop>, DbErr> {
+        let models = shared_drop::Entity::find().all(&self.db).await?;
+        Ok(models
+            .into_iter()
+            .map(|m| SharedDrop {
+                table_name: m.table_name,
+                obj_id: m.obj_id.map(|id| id as u32),
+                shared_table: m.shared_table,
+                min_amount: m.min_amount as u32,
+                max_amount: m.max_amount as u32,
+                noted: m.noted,
+                numerator: m.numerator as u32,
+                denominator: m.denominator as u32,
+            })
+            .collect())
+    }
 }
@@ -15,6 +15,9 @@ pub struct Model {
     pub auto_retaliate: bool,
     pub spec_energy: i16,
     pub current_hp: i16,
+    pub prayer_points: Option<i16>,
+    pub private_chat: i16,
+    pub clan_channel: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
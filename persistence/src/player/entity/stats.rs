@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub struct StatEntry {
     pub level: u8,
     pub xp: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current: Option<u8>,
 }
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
@@ -24,6 +24,7 @@ pub struct PlayerData {
     pub look: [u16; 7],
     pub colors: [u8; 5],
     pub levels: [u8; 24],
+    pub current_levels: [u8; 24],
     pub xp: [u32; 24],
     pub inv: Vec<Option<(u16, u32)>>,
     pub worn: Vec<Option<(u16, u32)>>,
@@ -33,6 +34,11 @@ pub struct PlayerData {
     pub auto_retaliate: bool,
     pub spec_energy: u16,
     pub current_hp: u8,
+    pub prayer_points: Option<u8>,
+    pub private_chat: u8,
+    pub friends: Vec<String>,
+    pub ignores: Vec<String>,
+    pub clan_channel: Option<String>,
 }
 
 #[async_trait]
@@ -75,9 +81,11 @@ impl PlayerData {
             serde_json::from_value(stat_model.stats).map_err(|e| DbErr::Type(e.to_string()))?;
 
         let mut levels = [1u8; 24];
+        let mut current_levels = [1u8; 24];
         let mut xp = [0u32; 24];
         for (i, entry) in stat_entries.iter().enumerate().take(24) {
             levels[i] = entry.level;
+            current_levels[i] = entry.current.unwrap_or(entry.level);
             xp[i] = entry.xp;
         }
 
@@ -125,6 +133,7 @@ impl PlayerData {
             look,
             colors,
             levels,
+            current_levels,
             xp,
             inv,
             worn,
@@ -134,6 +143,11 @@ impl PlayerData {
             auto_retaliate: player.auto_retaliate,
             spec_energy: player.spec_energy as u16,
             current_hp: player.current_hp as u8,
+            prayer_points: player.prayer_points.map(|v| v as u8),
+            private_chat: player.private_chat as u8,
+            friends: Vec::new(),
+            ignores: Vec::new(),
+            clan_channel: player.clan_channel,
         })
     }
 }
@@ -227,8 +241,13 @@ impl PlayerRepository for PgPlayerRepository {
         let stat_entries: Vec<StatEntry> = data
             .levels
             .iter()
+            .zip(data.current_levels.iter())
             .zip(data.xp.iter())
-            .map(|(&level, &xp)| StatEntry { level, xp })
+            .map(|((&level, &current), &xp)| StatEntry {
+                level,
+                xp,
+                current: (current != level).then_some(current),
+            })
             .collect();
 
         let stats_json = serde_json::to_value(&stat_entries).map_err(|e| DbErr::Type(e.to_string()))?;
@@ -244,6 +263,12 @@ impl PlayerRepository for PgPlayerRepository {
             .col_expr(player::Column::AutoRetaliate, Expr::value(data.auto_retaliate))
             .col_expr(player::Column::SpecEnergy, Expr::value(data.spec_energy as i16))
             .col_expr(player::Column::CurrentHp, Expr::value(data.current_hp as i16))
+            .col_expr(
+                player::Column::PrayerPoints,
+                Expr::value(data.prayer_points.map(|v| v as i16)),
+            )
+            .col_expr(player::Column::PrivateChat, Expr::value(data.private_chat as i16))
+            .col_expr(player::Column::ClanChannel, Expr::value(data.clan_channel.clone()))
             .exec(&self.db)
             .await?;
 
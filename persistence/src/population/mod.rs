@@ -0,0 +1,5 @@
+pub(crate) mod entity;
+mod repository;
+
+pub use repository::PopulationRepository;
+pub(crate) use repository::{PgPopulationRepository, PgPopulationRepositoryParameters};
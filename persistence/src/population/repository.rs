@@ -0,0 +1,62 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use sea_orm::{
+    prelude::{DateTimeUtc, Expr},
+    sea_query::OnConflict,
+    *,
+};
+use shaku::{Component, Interface};
+
+use super::entity::{self, Column, Entity as PopulationEntity};
+
+/// Player counts each world publishes so the others can show them in the world list.
+#[async_trait]
+pub trait PopulationRepository: Interface {
+    async fn publish(&self, world_id: u16, player_count: usize) -> Result<(), DbErr>;
+    /// Counts of every world that has published within `max_age`.
+    async fn find_live(&self, max_age: Duration) -> Result<Vec<(u16, usize)>, DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = PopulationRepository)]
+pub struct PgPopulationRepository {
+    #[shaku(default)]
+    db: DatabaseConnection,
+}
+
+#[async_trait]
+impl PopulationRepository for PgPopulationRepository {
+    async fn publish(&self, world_id: u16, player_count: usize) -> Result<(), DbErr> {
+        let model = entity::ActiveModel {
+            world_id: Set(world_id as i16),
+            player_count: Set(player_count as i32),
+            ..Default::default()
+        };
+
+        PopulationEntity::insert(model)
+            .on_conflict(
+                OnConflict::column(Column::WorldId)
+                    .update_columns([Column::PlayerCount, Column::UpdatedAt])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_live(&self, max_age: Duration) -> Result<Vec<(u16, usize)>, DbErr> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let cutoff = DateTimeUtc::from_timestamp(now.saturating_sub(max_age).as_secs() as i64, 0).unwrap_or_default();
+        let models = PopulationEntity::find()
+            .filter(Expr::col(Column::UpdatedAt).gte(cutoff.fixed_offset()))
+            .all(&self.db)
+            .await?;
+
+        Ok(models
+            .into_iter()
+            .map(|m| (m.world_id as u16, m.player_count.max(0) as usize))
+            .collect())
+    }
+}
@@ -0,0 +1,37 @@
+pub(crate) mod entity;
+mod repository;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+pub use repository::{NewPunishment, PunishmentRepository};
+pub(crate) use repository::{PgPunishmentRepository, PgPunishmentRepositoryParameters};
+use sea_orm::prelude::DateTimeWithTimeZone;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+pub enum PunishmentKind {
+    Mute = 0,
+    Ban = 1,
+    IpBan = 2,
+}
+
+#[derive(Debug, Clone)]
+pub struct Punishment {
+    pub id: i64,
+    pub account_id: i64,
+    pub kind: PunishmentKind,
+    pub ip: Option<String>,
+    pub reason: String,
+    pub staff_id: Option<i64>,
+    pub expires_at: Option<DateTimeWithTimeZone>,
+}
+
+impl Punishment {
+    /// Time left before this expires, or `None` if it is permanent.
+    pub fn remaining(&self) -> Option<Duration> {
+        let expires_at = self.expires_at?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Some(Duration::from_secs(expires_at.timestamp().max(0) as u64).saturating_sub(now))
+    }
+}
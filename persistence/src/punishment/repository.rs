@@ -0,0 +1,119 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use sea_orm::{
+    prelude::{DateTimeUtc, Expr},
+    *,
+};
+use shaku::{Component, Interface};
+
+use super::{
+    Punishment, PunishmentKind,
+    entity::{self, Column, Entity as PunishmentEntity},
+};
+
+pub struct NewPunishment {
+    pub account_id: i64,
+    pub kind: PunishmentKind,
+    pub ip: Option<String>,
+    pub reason: String,
+    pub staff_id: Option<i64>,
+    /// `None` for a permanent punishment.
+    pub duration: Option<Duration>,
+}
+
+impl TryFrom<entity::Model> for Punishment {
+    type Error = DbErr;
+
+    fn try_from(model: entity::Model) -> Result<Self, Self::Error> {
+        let kind = PunishmentKind::try_from(model.kind as u8).map_err(|e| DbErr::Type(e.to_string()))?;
+
+        Ok(Punishment {
+            id: model.id,
+            account_id: model.account_id,
+            kind,
+            ip: model.ip,
+            reason: model.reason,
+            staff_id: model.staff_id,
+            expires_at: model.expires_at,
+        })
+    }
+}
+
+#[async_trait]
+pub trait PunishmentRepository: Interface {
+    /// Unexpired punishments on the account, plus any IP ban on `ip`.
+    async fn find_active(&self, account_id: i64, ip: &str) -> Result<Vec<Punishment>, DbErr>;
+    async fn create(&self, punishment: NewPunishment) -> Result<Punishment, DbErr>;
+    async fn revoke(&self, account_id: i64, kinds: &[PunishmentKind]) -> Result<u64, DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = PunishmentRepository)]
+pub struct PgPunishmentRepository {
+    #[shaku(default)]
+    db: DatabaseConnection,
+}
+
+#[async_trait]
+impl PunishmentRepository for PgPunishmentRepository {
+    async fn find_active(&self, account_id: i64, ip: &str) -> Result<Vec<Punishment>, DbErr> {
+        let ip_ban = Condition::all()
+            .add(Column::Kind.eq(u8::from(PunishmentKind::IpBan) as i16))
+            .add(Column::Ip.eq(ip));
+
+        PunishmentEntity::find()
+            .filter(Column::Revoked.eq(false))
+            .filter(
+                Condition::any()
+                    .add(Column::ExpiresAt.is_null())
+                    .add(Expr::col(Column::ExpiresAt).gt(Expr::current_timestamp())),
+            )
+            .filter(Condition::any().add(Column::AccountId.eq(account_id)).add(ip_ban))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(Punishment::try_from)
+            .collect()
+    }
+
+    async fn create(&self, punishment: NewPunishment) -> Result<Punishment, DbErr> {
+        let expires_at = match punishment.duration {
+            Some(d) => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+                let expires = now
+                    .checked_add(d)
+                    .and_then(|t| i64::try_from(t.as_secs()).ok())
+                    .and_then(|secs| DateTimeUtc::from_timestamp(secs, 0))
+                    .ok_or_else(|| DbErr::Custom(format!("punishment duration {d:?} is out of range")))?;
+                Some(expires.fixed_offset())
+            }
+            None => None,
+        };
+
+        let model = entity::ActiveModel {
+            account_id: Set(punishment.account_id),
+            kind: Set(u8::from(punishment.kind) as i16),
+            ip: Set(punishment.ip),
+            reason: Set(punishment.reason),
+            staff_id: Set(punishment.staff_id),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        };
+
+        model.insert(&self.db).await.and_then(Punishment::try_from)
+    }
+
+    async fn revoke(&self, account_id: i64, kinds: &[PunishmentKind]) -> Result<u64, DbErr> {
+        let kinds = kinds.iter().map(|k| u8::from(*k) as i16);
+        let result = PunishmentEntity::update_many()
+            .col_expr(Column::Revoked, Expr::value(true))
+            .filter(Column::AccountId.eq(account_id))
+            .filter(Column::Kind.is_in(kinds))
+            .filter(Column::Revoked.eq(false))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,35 @@
+pub mod friend {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "player_friends")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub player_id: i64,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub username: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod ignore {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "player_ignores")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub player_id: i64,
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub username: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
@@ -0,0 +1,5 @@
+pub(crate) mod entity;
+mod repository;
+
+pub use repository::SocialRepository;
+pub(crate) use repository::{PgSocialRepository, PgSocialRepositoryParameters};
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sea_orm::*;
+use shaku::{Component, Interface};
+
+use super::entity::{friend, ignore};
+use crate::PlayerData;
+
+#[async_trait]
+pub trait SocialRepository: Interface {
+    async fn find_friends(&self, player_id: i64) -> Result<Vec<String>, DbErr>;
+    async fn find_ignores(&self, player_id: i64) -> Result<Vec<String>, DbErr>;
+    async fn save(&self, data: &PlayerData) -> Result<(), DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = SocialRepository)]
+pub struct PgSocialRepository {
+    #[shaku(default)]
+    db: DatabaseConnection,
+}
+
+#[async_trait]
+impl SocialRepository for PgSocialRepository {
+    async fn find_friends(&self, player_id: i64) -> Result<Vec<String>, DbErr> {
+        let models = friend::Entity::find()
+            .filter(friend::Column::PlayerId.eq(player_id))
+            .all(&self.db)
+            .await?;
+        Ok(models.into_iter().map(|m| m.username).collect())
+    }
+
+    async fn find_ignores(&self, player_id: i64) -> Result<Vec<String>, DbErr> {
+        let models = ignore::Entity::find()
+            .filter(ignore::Column::PlayerId.eq(player_id))
+            .all(&self.db)
+            .await?;
+        Ok(models.into_iter().map(|m| m.username).collect())
+    }
+
+    async fn save(&self, data: &PlayerData) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        friend::Entity::delete_many()
+            .filter(friend::Column::PlayerId.eq(data.player_id))
+            .exec(&txn)
+            .await?;
+        if !data.friends.is_empty() {
+            friend::Entity::insert_many(data.friends.iter().map(|username| friend::ActiveModel {
+                player_id: Set(data.player_id),
+                username: Set(username.clone()),
+            }))
+            .exec(&txn)
+            .await?;
+        }
+
+        ignore::Entity::delete_many()
+            .filter(ignore::Column::PlayerId.eq(data.player_id))
+            .exec(&txn)
+            .await?;
+        if !data.ignores.is_empty() {
+            ignore::Entity::insert_many(data.ignores.iter().map(|username| ignore::ActiveModel {
+                player_id: Set(data.player_id),
+                username: Set(username.clone()),
+            }))
+            .exec(&txn)
+            .await?;
+        }
+
+        txn.commit().await
+    }
+}
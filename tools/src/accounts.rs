@@ -0,0 +1,110 @@
+use anyhow::{Context, Result, anyhow, bail};
+use config::{Config, Environment, File};
+use persistence::{
+    Account, AccountRepository, DatabaseConfig, Rights, hash_password, is_valid_password, shaku::HasComponent,
+};
+use serde::Deserialize;
+use util::{is_valid_username, normalize_name};
+
+const USAGE: &str = "Usage: accounts <command> <username> [args]
+
+Commands:
+  create <username> <password> [standard|moderator|admin]
+  reset-password <username> <password>
+  promote <username> <standard|moderator|admin>
+  disable <username>
+  enable <username>
+
+The database is read from the [database] section of Config.toml, Config.local.toml
+or APP__DATABASE__URL, the same as the game server.";
+
+#[derive(Deserialize)]
+struct ToolConfig {
+    #[serde(default)]
+    database: DatabaseConfig,
+}
+
+fn parse_rights(rights: &str) -> Result<Rights> {
+    match rights.to_ascii_lowercase().as_str() {
+        "standard" | "0" => Ok(Rights::Standard),
+        "moderator" | "mod" | "1" => Ok(Rights::Moderator),
+        "admin" | "2" => Ok(Rights::Admin),
+        _ => bail!("unknown rights '{rights}', expected standard, moderator or admin"),
+    }
+}
+
+fn hash(password: &str) -> Result<String> {
+    if !is_valid_password(password) {
+        bail!("passwords must be 5-20 printable characters without spaces");
+    }
+    hash_password(password).map_err(|e| anyhow!("failed to hash password: {e}"))
+}
+
+async fn find(accounts: &dyn AccountRepository, username: &str) -> Result<Account> {
+    accounts
+        .find_by_username(username)
+        .await?
+        .with_context(|| format!("no account named {username}"))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (Some(command), Some(username)) = (args.first(), args.get(1)) else {
+        bail!(USAGE);
+    };
+    let username = normalize_name(username);
+
+    let config: ToolConfig = Config::builder()
+        .add_source(File::with_name("Config").required(false))
+        .add_source(File::with_name("Config.local").required(false))
+        .add_source(Environment::with_prefix("APP").separator("__"))
+        .build()?
+        .try_deserialize()?;
+    let module = persistence::connect(&config.database).await?;
+    let accounts: &dyn AccountRepository = module.resolve_ref();
+
+    match (command.as_str(), &args[2..]) {
+        ("create", [password, rest @ ..]) if rest.len() <= 1 => {
+            if !is_valid_username(&username) {
+                bail!("usernames must be 1-12 letters, digits or single spaces");
+            }
+            let rights = rest
+                .first()
+                .map(|r| parse_rights(r))
+                .transpose()?
+                .unwrap_or(Rights::Standard);
+            let account = accounts.create(&username, &hash(password)?, rights).await?;
+            println!(
+                "Created {} ({:?}) with id {}",
+                account.display_name(),
+                account.rights,
+                account.id
+            );
+        }
+        ("reset-password", [password]) => {
+            let account = find(accounts, &username).await?;
+            accounts.update_password(account.id, &hash(password)?).await?;
+            println!("Reset the password of {}", account.display_name());
+        }
+        ("promote", [rights]) => {
+            let account = find(accounts, &username).await?;
+            let rights = parse_rights(rights)?;
+            accounts.update_rights(account.id, rights).await?;
+            println!("{} now has {:?} rights", account.display_name(), rights);
+        }
+        ("disable" | "enable", []) => {
+            let account = find(accounts, &username).await?;
+            let disabled = command == "disable";
+            accounts.set_disabled(account.id, disabled).await?;
+            println!(
+                "{} {}",
+                if disabled { "Disabled" } else { "Enabled" },
+                account.display_name()
+            );
+        }
+        _ => bail!(USAGE),
+    }
+
+    Ok(())
+}
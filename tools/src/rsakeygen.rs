@@ -0,0 +1,28 @@
+use std::fs;
+
+use anyhow::{Context, Result, bail};
+use util::RsaKeyPair;
+
+const DEFAULT_BITS: usize = 1024;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 || args.len() > 3 {
+        bail!("Usage: rsa-keygen <output-path> [bits]\n\nExample:\n  rsa-keygen rsa.toml 1024");
+    }
+
+    let path = &args[1];
+    let bits = match args.get(2) {
+        Some(bits) => bits.parse().context("bits must be a positive integer")?,
+        None => DEFAULT_BITS,
+    };
+
+    let key = RsaKeyPair::generate(bits)?;
+    fs::write(path, key.to_toml()).with_context(|| format!("failed to write {path}"))?;
+
+    eprintln!("Wrote {bits}-bit keypair to {path}");
+    println!("modulus = {}", key.modulus);
+    println!("public_exponent = {}", key.public_exponent);
+
+    Ok(())
+}
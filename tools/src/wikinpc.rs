@@ -38,6 +38,21 @@ struct NpcStats {
     max_hit: u16,
 }
 
+struct WikiDrop {
+    category: &'static str,
+    source: DropSource,
+    min_amount: u32,
+    max_amount: u32,
+    noted: bool,
+    numerator: u32,
+    denominator: u32,
+}
+
+enum DropSource {
+    Item(String),
+    Table(&'static str),
+}
+
 fn parse_url(url: &str) -> Result<(String, Option<String>)> {
     let (url, fragment) = url
         .split_once('#')
@@ -118,22 +133,29 @@ fn parse_bonus(params: &HashMap<String, String>, key: &str, ver: &str) -> i16 {
         .unwrap_or(0)
 }
 
-async fn fetch_stats(page: &str, fragment: &Option<String>) -> Result<NpcStats> {
-    let client = reqwest::Client::builder().user_agent("rune.rs wiki-npc tool").build()?;
-
-    let url = format!("{WIKI_API}?action=parse&page={page}&prop=wikitext&redirects=1&format=json");
+async fn fetch_wikitext(client: &reqwest::Client, page: &str) -> Result<String> {
     let resp: ApiResponse = client
-        .get(&url)
+        .get(WIKI_API)
+        .query(&[
+            ("action", "parse"),
+            ("page", page),
+            ("prop", "wikitext"),
+            ("redirects", "1"),
+            ("format", "json"),
+        ])
         .send()
         .await
         .context("request to wiki API failed")?
         .json()
         .await
-        .context("failed to parse wiki API response")?;
+        .with_context(|| format!("failed to parse wiki API response for {page}"))?;
 
-    let wikitext = resp.parse.wikitext.content;
+    Ok(resp.parse.wikitext.content)
+}
+
+fn parse_stats(wikitext: &str, page: &str, fragment: &Option<String>) -> Result<NpcStats> {
     let params =
-        parse_infobox(&wikitext, "Infobox Monster").context("could not find {{Infobox Monster}} on this page")?;
+        parse_infobox(wikitext, "Infobox Monster").context("could not find {{Infobox Monster}} on this page")?;
 
     let ver = resolve_version(&params, fragment);
     let name = get_param(&params, "name", &ver).unwrap_or_else(|| page.replace('_', " "));
@@ -166,6 +188,114 @@ async fn fetch_stats(page: &str, fragment: &Option<String>) -> Result<NpcStats>
     })
 }
 
+fn parse_rarity(rarity: &str) -> Option<(u32, u32)> {
+    let rarity = rarity.trim().trim_start_matches('~').replace(',', "");
+    if rarity.eq_ignore_ascii_case("always") {
+        return Some((1, 1));
+    }
+
+    let (num, den) = rarity.split_once('/')?;
+    let (num, den) = (num.trim(), den.trim());
+    if let (Ok(num), Ok(den)) = (num.parse::<u32>(), den.parse::<u32>()) {
+        return Some((num, den));
+    }
+
+    let (num, den) = (num.parse::<f64>().ok()?, den.parse::<f64>().ok()?);
+    Some(((num * 100.0).round() as u32, (den * 100.0).round() as u32))
+}
+
+fn parse_quantity(quantity: &str) -> Option<(u32, u32, bool)> {
+    let noted = quantity.to_lowercase().contains("noted");
+    let amounts: Vec<u32> = quantity
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    let min = *amounts.iter().min()?;
+    let max = *amounts.iter().max()?;
+    Some((min, max, noted))
+}
+
+fn parse_drops(wikitext: &str) -> Vec<WikiDrop> {
+    let mut drops = Vec::new();
+    let mut tertiary = false;
+
+    for line in wikitext.lines().map(str::trim) {
+        if line.starts_with("==") {
+            tertiary = line.to_lowercase().contains("tertiary");
+            continue;
+        }
+
+        let (source, params) = if let Some(params) = parse_infobox(line, "DropsLine") {
+            let Some(name) = params.get("name") else { continue };
+            (DropSource::Item(name.clone()), params)
+        } else if let Some(params) = parse_infobox(line, "RareDropTable") {
+            (DropSource::Table("rare"), params)
+        } else if let Some(params) = parse_infobox(line, "GemDropTable") {
+            (DropSource::Table("gem"), params)
+        } else {
+            continue;
+        };
+
+        let rarity = params.get("rarity").map(String::as_str).unwrap_or_default();
+        let Some((numerator, denominator)) = parse_rarity(rarity) else {
+            eprintln!("  skipping drop with unsupported rarity '{rarity}': {line}");
+            continue;
+        };
+        let quantity = params.get("quantity").map(String::as_str).unwrap_or("1");
+        let Some((min_amount, max_amount, noted)) = parse_quantity(quantity) else {
+            eprintln!("  skipping drop with unsupported quantity '{quantity}': {line}");
+            continue;
+        };
+
+        let category = match (rarity.eq_ignore_ascii_case("always"), tertiary) {
+            (true, _) => "always",
+            (false, true) => "tertiary",
+            (false, false) => "main",
+        };
+
+        drops.push(WikiDrop {
+            category,
+            source,
+            min_amount,
+            max_amount,
+            noted,
+            numerator,
+            denominator,
+        });
+    }
+
+    drops
+}
+
+async fn fetch_obj_id(client: &reqwest::Client, cache: &mut HashMap<String, u32>, name: &str) -> Result<u32> {
+    if let Some(&id) = cache.get(name) {
+        return Ok(id);
+    }
+
+    let wikitext = fetch_wikitext(client, name).await?;
+    let params = parse_infobox(&wikitext, "Infobox Item").context("could not find {{Infobox Item}}")?;
+    let id = params
+        .get("id")
+        .or_else(|| params.get("id1"))
+        .and_then(|v| v.split(',').next())
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .context("item has no id")?;
+
+    cache.insert(name.to_string(), id);
+    Ok(id)
+}
+
+fn print_drops_sql(npc_id: u32, name: &str, rows: &[String]) {
+    println!("\n-- {name} drops\nDELETE FROM npc_drops WHERE npc_id = {npc_id};");
+    if rows.is_empty() {
+        return;
+    }
+    println!(
+        "INSERT INTO npc_drops (npc_id, category, obj_id, shared_table, min_amount, max_amount, noted, numerator, denominator)\nVALUES\n    {};",
+        rows.join(",\n    ")
+    );
+}
+
 fn print_sql(npc_id: u32, s: &NpcStats) {
     println!(
         r#"-- {name}
@@ -211,7 +341,9 @@ async fn main() -> Result<()> {
     let npc_id: u32 = args[2].parse().context("npc-id must be a positive integer")?;
 
     eprintln!("Fetching https://oldschool.runescape.wiki/w/{page} ...");
-    let stats = fetch_stats(&page, &fragment).await?;
+    let client = reqwest::Client::builder().user_agent("rune.rs wiki-npc tool").build()?;
+    let wikitext = fetch_wikitext(&client, &page).await?;
+    let stats = parse_stats(&wikitext, &page, &fragment)?;
 
     eprintln!(
         "  {} — HP:{} ATK:{} STR:{} DEF:{} MaxHit:{} Speed:{}",
@@ -219,5 +351,28 @@ async fn main() -> Result<()> {
     );
 
     print_sql(npc_id, &stats);
+
+    let drops = parse_drops(&wikitext);
+    let mut obj_ids = HashMap::new();
+    let mut rows = Vec::with_capacity(drops.len());
+    for drop in &drops {
+        let (obj_id, table) = match &drop.source {
+            DropSource::Item(name) => match fetch_obj_id(&client, &mut obj_ids, name).await {
+                Ok(id) => (id.to_string(), "NULL".to_string()),
+                Err(e) => {
+                    eprintln!("  skipping drop '{name}': {e:#}");
+                    continue;
+                }
+            },
+            DropSource::Table(table) => ("NULL".to_string(), format!("'{table}'")),
+        };
+        rows.push(format!(
+            "({npc_id}, '{}', {obj_id}, {table}, {}, {}, {}, {}, {})",
+            drop.category, drop.min_amount, drop.max_amount, drop.noted, drop.numerator, drop.denominator
+        ));
+    }
+
+    eprintln!("  {} of {} drops resolved", rows.len(), drops.len());
+    print_drops_sql(npc_id, &stats.name, &rows);
     Ok(())
 }
@@ -1,9 +1,28 @@
+const MAX_LEN: usize = 12;
+
+pub fn encode_base37(name: &str) -> i64 {
+    let mut value = 0i64;
+    for c in name.chars().take(MAX_LEN) {
+        value *= 37;
+        match c {
+            'a'..='z' => value += 1 + (c as i64 - 'a' as i64),
+            'A'..='Z' => value += 1 + (c as i64 - 'A' as i64),
+            '0'..='9' => value += 27 + (c as i64 - '0' as i64),
+            _ => {}
+        }
+    }
+
+    while value != 0 && value % 37 == 0 {
+        value /= 37;
+    }
+    value
+}
+
 pub fn decode_base37(mut value: i64) -> String {
     if value == 0 {
         return String::new();
     }
 
-    const MAX_LEN: usize = 12;
     let mut chars = Vec::with_capacity(MAX_LEN);
 
     while value != 0 && chars.len() < MAX_LEN {
@@ -22,3 +41,13 @@ pub fn decode_base37(mut value: i64) -> String {
     chars.reverse();
     chars.into_iter().collect()
 }
+
+/// Whether `name` can be registered: 1-12 letters, digits and single spaces or
+/// underscores between words, surviving a round trip through base37 unchanged.
+pub fn is_valid_username(name: &str) -> bool {
+    let name = name.to_ascii_lowercase().replace(' ', "_");
+    if name.is_empty() || name.len() > MAX_LEN || name.starts_with('_') || name.ends_with('_') || name.contains("__") {
+        return false;
+    }
+    name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') && decode_base37(encode_base37(&name)) == name
+}
@@ -1,5 +1,5 @@
 use bytes::BytesMut;
-use tokio_util::bytes::{Buf, BufMut};
+use tokio_util::bytes::{Buf, BufMut, TryGetError};
 
 const NULL_TERMINATOR: u8 = 0;
 
@@ -72,6 +72,51 @@ pub trait BufExt: Buf {
         total
     }
 
+    fn try_get_u8_sub(&mut self) -> Result<u8, TryGetError> {
+        Ok(128u8.wrapping_sub(self.try_get_u8()?))
+    }
+
+    fn try_get_u8_add(&mut self) -> Result<u8, TryGetError> {
+        Ok(self.try_get_u8()?.wrapping_sub(128))
+    }
+
+    fn try_get_u8_neg(&mut self) -> Result<u8, TryGetError> {
+        Ok(0u8.wrapping_sub(self.try_get_u8()?))
+    }
+
+    fn try_get_u16_add(&mut self) -> Result<u16, TryGetError> {
+        self.ensure_remaining(2)?;
+        Ok(self.get_u16_add())
+    }
+
+    fn try_get_u16_le_add(&mut self) -> Result<u16, TryGetError> {
+        self.ensure_remaining(2)?;
+        Ok(self.get_u16_le_add())
+    }
+
+    fn try_get_u32_mid_le(&mut self) -> Result<u32, TryGetError> {
+        self.ensure_remaining(4)?;
+        Ok(self.get_u32_mid_le())
+    }
+
+    fn try_get_smart(&mut self) -> Result<u16, TryGetError> {
+        match self.chunk().first() {
+            Some(&peek) if peek < 128 => Ok(self.get_u8() as u16),
+            Some(_) => Ok(self.try_get_u16()? - 32768),
+            None => Err(TryGetError {
+                requested: 1,
+                available: 0,
+            }),
+        }
+    }
+
+    fn ensure_remaining(&self, requested: usize) -> Result<(), TryGetError> {
+        match self.remaining() {
+            available if available < requested => Err(TryGetError { requested, available }),
+            _ => Ok(()),
+        }
+    }
+
     fn get_string(&mut self) -> String {
         let mut bytes = Vec::new();
 
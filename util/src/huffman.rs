@@ -79,9 +79,7 @@ impl HuffmanTable {
         let mut node = 0usize;
         let mut byte_idx = 0;
 
-        'outer: loop {
-            let byte = data[byte_idx];
-
+        'outer: while let Some(&byte) = data.get(byte_idx) {
             for bit in (0..8).rev() {
                 if byte & (1 << bit) == 0 {
                     node += 1;
@@ -4,8 +4,9 @@ mod huffman;
 mod rsa;
 mod text;
 
-pub use base37::decode_base37;
+pub use base37::{decode_base37, encode_base37, is_valid_username};
 pub use bytes::{BitsMut, BufExt, BytesMutExt};
 pub use huffman::HuffmanTable;
-pub use rsa::{EXPONENT, MODULUS, rsa_decrypt};
-pub use text::{format_display_name, format_sentence};
+pub use text::{format_display_name, format_sentence, normalize_name};
+
+pub use self::rsa::RsaKeyPair;
@@ -6,6 +6,10 @@ pub fn format_display_name(name: &str) -> String {
         .join(" ")
 }
 
+pub fn normalize_name(name: &str) -> String {
+    name.trim().to_ascii_lowercase().replace(' ', "_")
+}
+
 pub fn format_sentence(text: &str) -> String {
     text.chars()
         .scan(true, |cap, c| {